@@ -1,5 +1,6 @@
-//! Proc-macro for implementing `Fn/Pipe*` variants for tuples of a given size;
-//! it is only here for this one purpose and no other, should not be used elsewhere
+//! Proc-macros for implementing `Fn/Pipe*` variants (and the supporting pipe-building traits) for
+//! tuples of a given size; they are only here for this one purpose and no other, should not be
+//! used elsewhere
 
 #![allow(clippy::arbitrary_source_item_ordering)]
 
@@ -7,40 +8,322 @@ extern crate proc_macro;
 
 use extend::ext;
 use proc_macro::TokenStream;
+use proc_macro2::Literal;
 use quote::quote;
-use syn::{parse_macro_input, LitInt};
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, Ident, LitInt, Token};
 
 type TokS2 = proc_macro2::TokenStream;
 
+/// The arguments of [`impl_fn_pipe_for_tuple`], i.e. the maximum tuple size optionally followed
+/// by the `stable` flavour marker
+struct FnPipeArgs {
+    max_tuple_size: LitInt,
+    stable: bool,
+}
+
+impl Parse for FnPipeArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let max_tuple_size = input.parse()?;
+        let stable = if input.parse::<Option<Token![,]>>()?.is_some() {
+            let flavour = input.parse::<Ident>()?;
+            if flavour != "stable" {
+                return Err(syn::Error::new(flavour.span(), "expected `stable`"));
+            }
+            true
+        } else {
+            false
+        };
+        Ok(Self {
+            max_tuple_size,
+            stable,
+        })
+    }
+}
+
+/// The tokens in which the flavours of tuple pipes differ, from which
+/// [`impl_pipe_flavour_for_tuple`] generates their `Fn`, `FnMut` and `FnOnce` variants
+struct PipeFlavour {
+    /// The `Fn*` trait bounding the stages, the pipe trait, its run method and the `Fn*` call
+    /// method of the `Fn`, `FnMut` and `FnOnce` variants, in that order
+    names: [[&'static str; 4]; 3],
+    /// The bound on the `Args` parameter, if any
+    args_bound: Option<TokS2>,
+    /// The bound on the last stage, if any
+    last_stage_bound: Option<TokS2>,
+    /// The ABI of the run methods, if any
+    abi: Option<TokS2>,
+    /// The lints allowed on every implementation
+    allowed_lints: TokS2,
+    /// The input of a stage, given the type of the stage before it
+    stage_input: fn(&TokS2) -> TokS2,
+    /// The bounds on the stages on top of their `Fn*` bounds, given all the stages
+    extra_type_constraints: fn(&[TokS2]) -> Vec<TokS2>,
+    /// The input of a stage, given the call of the stage before it
+    forward: fn(TokS2) -> TokS2,
+    /// The `Output` of the `FnOnce` variant, given the last stage
+    output: fn(&TokS2) -> TokS2,
+    /// The return type of the run methods
+    return_type: TokS2,
+    /// The body of the run methods, given the call of the last stage
+    body: fn(TokS2) -> TokS2,
+}
+
+/// Implements a flavour of the `Fn/Pipe*` variants for tuples of upto the given size, chaining
+/// every stage into the next with the call and return tokens of that flavour
+#[allow(clippy::indexing_slicing, clippy::arithmetic_side_effects)]
+fn impl_pipe_flavour_for_tuple(max_tuple_size: usize, flavour: &PipeFlavour) -> TokS2 {
+    // generate generic function type-names, to be used later everywhere
+    let fn_type_names = (0..max_tuple_size)
+        .map(|i| format!("_{i}").parse_unchecked())
+        .collect::<Vec<_>>();
+
+    let PipeFlavour {
+        ref args_bound,
+        ref last_stage_bound,
+        ref abi,
+        ref allowed_lints,
+        ref return_type,
+        ..
+    } = *flavour;
+
+    // generic impl block parametrised on the variant and number of params
+    let impl_per_type_and_n = |n: usize,
+                               [fn_name, fn_pipe_name, run, call]: [&str; 4],
+                               ref_style: Option<TokS2>| {
+        let (fn_name, fn_pipe_name, run, call) = (
+            fn_name.parse_unchecked(),
+            fn_pipe_name.parse_unchecked(),
+            run.parse_unchecked(),
+            call.parse_unchecked(),
+        );
+        let fn_type_names = &fn_type_names[0..n];
+        let (first_fn_type, last_fn_type) = (&fn_type_names[0], &fn_type_names[n - 1]);
+
+        // the first stage takes the pipe's arguments, and every other the output of the one before
+        let mut type_constraints = vec![quote! { #first_fn_type: #fn_name<Args> }];
+        for i in 1..n {
+            let this_fn = &fn_type_names[i];
+            let input = (flavour.stage_input)(&fn_type_names[i - 1]);
+            type_constraints.push(quote! { #this_fn: #fn_name<(#input,)> });
+        }
+        type_constraints.extend((flavour.extra_type_constraints)(fn_type_names));
+
+        // recursive call implementation starting from the base
+        let mut call_impl = quote! { self.0 .#call(args) };
+        for i in 1..n {
+            let tuple_access = format!("self.{i}").parse_unchecked();
+            let input = (flavour.forward)(call_impl);
+            call_impl = quote! { #tuple_access.#call((#input,)) };
+        }
+        let body = (flavour.body)(call_impl);
+
+        // only the `FnOnce` variant, which takes `self`, specifies the associated `type Output`
+        let output = ref_style.is_none().then(|| {
+            let output = (flavour.output)(last_fn_type);
+            quote! { type Output = #output; }
+        });
+
+        quote! {
+            #allowed_lints
+            impl<Args #args_bound, #(#fn_type_names),* #last_stage_bound> #fn_pipe_name<Args> for (#(#fn_type_names),*,)
+            where #(#type_constraints),*
+            {
+                #output
+
+                #[inline]
+                #abi fn #run(#ref_style self, args: Args) -> #return_type {
+                    #body
+                }
+            }
+        }
+    };
+
+    // we need to implement every variant for all tuple sizes 1 through-to `n`
+    let mut impls = TokS2::new();
+    for n in 1..=max_tuple_size {
+        let [fn_names, fn_mut_names, fn_once_names] = flavour.names;
+        impls.extend(impl_per_type_and_n(n, fn_names, Some(quote! { & })));
+        impls.extend(impl_per_type_and_n(n, fn_mut_names, Some(quote! { &mut })));
+        impls.extend(impl_per_type_and_n(n, fn_once_names, None));
+    }
+    impls
+}
+
+/// Parses the maximum tuple size passed to the pipe macros, which must be greater than zero
+#[allow(clippy::missing_panics_doc)]
+fn parse_max_tuple_size(max_tuple_size: &LitInt) -> syn::Result<usize> {
+    let max_tuple_size = max_tuple_size.base10_parse::<usize>()?;
+    assert!(
+        max_tuple_size > 0,
+        "passed parameter must be greater than zero"
+    );
+    Ok(max_tuple_size)
+}
+
+/// Implements the `Fn/Pipe*` variants for tuples of upto the given size.
+///
+/// Passing `stable` after the size generates the stable-Rust flavour instead, which is built on
+/// the `TupleFn*` traits rather than the `Fn*` traits and doesn't use the `"rust-call"` ABI
+#[proc_macro]
+pub fn impl_fn_pipe_for_tuple(item: TokenStream) -> TokenStream {
+    // get the number of tuple parameters to implement this for, and the flavour
+    let FnPipeArgs {
+        max_tuple_size,
+        stable,
+    } = parse_macro_input!(item as FnPipeArgs);
+    let max_tuple_size = match parse_max_tuple_size(&max_tuple_size) {
+        Ok(num) => num,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    // the stable flavour can neither use the `"rust-call"` ABI, nor an unsized last stage
+    let flavour = if stable {
+        PipeFlavour {
+            names: [
+                ["TupleFn", "FnPipe", "run", "call_tuple"],
+                ["TupleFnMut", "FnMutPipe", "run_mut", "call_tuple_mut"],
+                ["TupleFnOnce", "FnOncePipe", "run_once", "call_tuple_once"],
+            ],
+            args_bound: None,
+            last_stage_bound: None,
+            abi: None,
+            ..fn_pipe_flavour()
+        }
+    } else {
+        fn_pipe_flavour()
+    };
+    impl_pipe_flavour_for_tuple(max_tuple_size, &flavour).into()
+}
+
+/// The nightly flavour of the `Fn/Pipe*` variants, which feed the output of every stage as-is
+fn fn_pipe_flavour() -> PipeFlavour {
+    PipeFlavour {
+        names: [
+            ["Fn", "FnPipe", "run", "call"],
+            ["FnMut", "FnMutPipe", "run_mut", "call_mut"],
+            ["FnOnce", "FnOncePipe", "run_once", "call_once"],
+        ],
+        args_bound: Some(quote! { : Tuple }),
+        last_stage_bound: Some(quote! { : ?Sized }),
+        abi: Some(quote! { extern "rust-call" }),
+        allowed_lints: quote! { #[allow(clippy::type_repetition_in_bounds)] },
+        stage_input: |prev_fn| quote! { #prev_fn::Output },
+        extra_type_constraints: |_| Vec::new(),
+        forward: |call| call,
+        output: |last_fn| quote! { #last_fn::Output },
+        return_type: quote! { Self::Output },
+        body: |call| call,
+    }
+}
+
+/// Implements the `TryFn/Pipe*` variants for tuples of upto the given size.
+///
+/// They unwrap the [`Try`](std::ops::Try) output of every stage with `?` before feeding it into
+/// the next, so the output of the last stage must absorb the residuals of all the stages before it
+#[proc_macro]
+pub fn impl_try_fn_pipe_for_tuple(item: TokenStream) -> TokenStream {
+    // get the number of tuple parameters to implement this for
+    let max_tuple_size = match parse_max_tuple_size(&parse_macro_input!(item as LitInt)) {
+        Ok(num) => num,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let flavour = PipeFlavour {
+        names: [
+            ["Fn", "TryFnPipe", "try_run", "call"],
+            ["FnMut", "TryFnMutPipe", "try_run_mut", "call_mut"],
+            ["FnOnce", "TryFnOncePipe", "try_run_once", "call_once"],
+        ],
+        stage_input: |prev_fn| quote! { <#prev_fn::Output as Try>::Output },
+        extra_type_constraints: |fn_type_names| {
+            let Some((last_fn, prev_fns)) = fn_type_names.split_last() else {
+                return Vec::new();
+            };
+            let mut type_constraints = prev_fns
+                .iter()
+                .map(|prev_fn| quote! { #prev_fn::Output: Try })
+                .collect::<Vec<_>>();
+            type_constraints.push(quote! {
+                #last_fn::Output: Try #(+ FromResidual<<#prev_fns::Output as Try>::Residual>)*
+            });
+            type_constraints
+        },
+        forward: |call| quote! { #call? },
+        ..fn_pipe_flavour()
+    };
+    impl_pipe_flavour_for_tuple(max_tuple_size, &flavour).into()
+}
+
+/// Implements the `AsyncFn/Pipe*` variants for tuples of upto the given size.
+///
+/// Every stage returns a [`Future`](std::future::Future), which is awaited before its output is
+/// fed into the next; the future has to own `self` when it is consumed, so the stages must all be
+/// sized
+#[proc_macro]
+pub fn impl_async_fn_pipe_for_tuple(item: TokenStream) -> TokenStream {
+    // get the number of tuple parameters to implement this for
+    let max_tuple_size = match parse_max_tuple_size(&parse_macro_input!(item as LitInt)) {
+        Ok(num) => num,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let flavour = PipeFlavour {
+        names: [
+            ["Fn", "AsyncFnPipe", "async_run", "call"],
+            ["FnMut", "AsyncFnMutPipe", "async_run_mut", "call_mut"],
+            ["FnOnce", "AsyncFnOncePipe", "async_run_once", "call_once"],
+        ],
+        last_stage_bound: None,
+        allowed_lints: quote! {
+            #[allow(clippy::type_repetition_in_bounds, clippy::future_not_send)]
+        },
+        stage_input: |prev_fn| quote! { <#prev_fn::Output as Future>::Output },
+        extra_type_constraints: |fn_type_names| {
+            fn_type_names
+                .iter()
+                .map(|this_fn| quote! { #this_fn::Output: Future })
+                .collect()
+        },
+        forward: |call| quote! { #call.await },
+        output: |last_fn| quote! { <#last_fn::Output as Future>::Output },
+        return_type: quote! { impl Future<Output = Self::Output> },
+        body: |call| quote! { async move { #call.await } },
+        ..fn_pipe_flavour()
+    };
+    impl_pipe_flavour_for_tuple(max_tuple_size, &flavour).into()
+}
+
+/// Implements the `ObservedFn/Pipe*` variants, as well as `StageCount`, for tuples of upto the
+/// given size; every stage is run through the observer, which is notified when it starts and ends
 #[allow(
-    clippy::unwrap_used,
     clippy::indexing_slicing,
     clippy::arithmetic_side_effects,
     clippy::missing_panics_doc,
     clippy::too_many_lines
 )]
 #[proc_macro]
-pub fn impl_fn_pipe_for_tuple(item: TokenStream) -> TokenStream {
+pub fn impl_observed_fn_pipe_for_tuple(item: TokenStream) -> TokenStream {
     // DEFINE CONSTANT TOKEN STREAMS UPFRONT
-    // token streams for Fn/Pipe* variants
-    let fn_pipe_names = (
+    // token streams for ObservedFn/Pipe* variants
+    let observed_fn_pipe_names = (
         (
-            "Fn".parse_unchecked(),
-            "FnPipe".parse_unchecked(),
-            "run".parse_unchecked(),
-            "call".parse_unchecked(),
+            "Stage".parse_unchecked(),
+            "ObservedFnPipe".parse_unchecked(),
+            "run_observed".parse_unchecked(),
+            "call_stage".parse_unchecked(),
         ),
         (
-            "FnMut".parse_unchecked(),
-            "FnMutPipe".parse_unchecked(),
-            "run_mut".parse_unchecked(),
-            "call_mut".parse_unchecked(),
+            "StageMut".parse_unchecked(),
+            "ObservedFnMutPipe".parse_unchecked(),
+            "run_mut_observed".parse_unchecked(),
+            "call_stage_mut".parse_unchecked(),
         ),
         (
-            "FnOnce".parse_unchecked(),
-            "FnOncePipe".parse_unchecked(),
-            "run_once".parse_unchecked(),
-            "call_once".parse_unchecked(),
+            "StageOnce".parse_unchecked(),
+            "ObservedFnOncePipe".parse_unchecked(),
+            "run_once_observed".parse_unchecked(),
+            "call_stage_once".parse_unchecked(),
         ),
     );
 
@@ -54,77 +337,91 @@ pub fn impl_fn_pipe_for_tuple(item: TokenStream) -> TokenStream {
         "passed parameter must be greater than zero"
     );
 
-    // generate generic function type-names, to be used later everywhere
-    let mut fn_type_names = Vec::with_capacity(max_tuple_size);
-    for i in 0..max_tuple_size {
-        fn_type_names.push(format!("_{i}").parse_unchecked());
-    }
+    // generate generic function type-names and intermediate value names, to be used everywhere
+    let fn_type_names = (0..max_tuple_size)
+        .map(|i| format!("_{i}").parse_unchecked())
+        .collect::<Vec<_>>();
+    let output_names = (0..max_tuple_size)
+        .map(|i| format!("output_{i}").parse_unchecked())
+        .collect::<Vec<_>>();
+    let kind_type_names = (0..max_tuple_size)
+        .map(|i| format!("K{i}").parse_unchecked())
+        .collect::<Vec<_>>();
 
     // create a middle type constraint (i.e. not the first one)
-    let middle_type_constraint = |prev_fn: TokS2, this_fn: TokS2, fn_name: TokS2| {
-        quote! {
-            #this_fn: #fn_name<(#prev_fn::Output,)>
-        }
-    };
+    let middle_type_constraint =
+        |prev_fn: &TokS2, this_fn: &TokS2, this_kind: &TokS2, fn_name: &TokS2| {
+            quote! {
+                #this_fn: #fn_name<(#prev_fn::Output,), #this_kind>
+            }
+        };
 
-    // create call implementation
-    let impl_call = |n: usize, call: TokS2, base: TokS2| {
-        let tuple_access = format!("self.{n}").parse_unchecked();
+    // create the observed call of a single stage, given its input
+    let impl_stage = |i: usize, n: usize, call: &TokS2, input: TokS2| {
+        let tuple_access = format!("self.{i}").parse_unchecked();
+        let fn_type = &fn_type_names[i];
+        let kind_type = &kind_type_names[i];
+        let output = &output_names[i];
+        let (index, count) = (Literal::usize_unsuffixed(i), Literal::usize_unsuffixed(n));
         quote! {
-            #tuple_access.#call((#base,))
+            let #output = observe_stage::<_, _, #fn_type, #kind_type, _>(
+                observer,
+                StageInfo::new(#index, #count, #tuple_access.stage_name()),
+                #input,
+                |input| #tuple_access.#call(input),
+            );
         }
     };
 
     // generic impl block parametrised on the variant and number of params
     let impl_per_type_and_n = |n: usize,
-                               (fn_name, fn_pipe_name, run, call): (TokS2, TokS2, TokS2, TokS2),
+                               (fn_name, observed_fn_pipe_name, run_observed, call): (
+        TokS2,
+        TokS2,
+        TokS2,
+        TokS2,
+    ),
                                extra: Option<TokS2>,
                                ref_style: Option<TokS2>| {
         // flatten the extra tokens
         let extra = extra.unwrap_or_default();
 
-        let fn_type_names_comma_sep = &fn_type_names[0..n].comma_separated();
-
-        // get name of first type and create the type constraint for the fist type
-        let first_fn_type = fn_type_names[0].clone();
-        let first_type_constraint = quote! {
-            #first_fn_type: #fn_name<Args>
-        };
-
-        // create the middle type constraint implementations
-        let middle_type_constraints = (1..n)
-            .map(|i| {
-                // get previous and current tokens
-                let prev_fn = fn_type_names[i - 1].clone();
-                let this_fn = fn_type_names[i].clone();
-
-                // create middle implementation
-                middle_type_constraint(prev_fn, this_fn, fn_name.clone())
-            })
-            .collect::<Vec<_>>();
+        let fn_type_names = &fn_type_names[0..n];
+        let kind_type_names = &kind_type_names[0..n];
 
-        // combine the two, and comma-separate them into a single block
-        let type_constraints = [vec![first_type_constraint], middle_type_constraints]
-            .concat()
-            .as_slice()
-            .comma_separated();
+        // create the type constraints, the first of which takes the pipe's arguments
+        let (first_fn_type, first_kind_type) = (&fn_type_names[0], &kind_type_names[0]);
+        let mut type_constraints =
+            vec![quote! { #first_fn_type: #fn_name<Args, #first_kind_type> }];
+        for i in 1..n {
+            type_constraints.push(middle_type_constraint(
+                &fn_type_names[i - 1],
+                &fn_type_names[i],
+                &kind_type_names[i],
+                &fn_name,
+            ));
+        }
+        let type_constraints = type_constraints.as_slice().comma_separated();
 
-        // recursive call implementation starting from the base
-        let mut call_impl = quote! { self.0 .#call(args) };
+        // sequential stage implementation, each stage being fed the previous output
+        let mut stages_impl = impl_stage(0, n, &call, quote! { args });
         for i in 1..n {
-            call_impl = impl_call(i, call.clone(), call_impl);
+            let prev_output = &output_names[i - 1];
+            stages_impl.extend(impl_stage(i, n, &call, quote! { (#prev_output,) }));
         }
+        let last_output = &output_names[n - 1];
 
         quote! {
-            #[allow(clippy::type_repetition_in_bounds)]
-            impl<Args: Tuple, #fn_type_names_comma_sep: ?Sized> #fn_pipe_name<Args> for (#fn_type_names_comma_sep,)
+            #[allow(clippy::type_repetition_in_bounds, clippy::redundant_closure_for_method_calls)]
+            impl<Args: Tuple, #(#fn_type_names),*, #(#kind_type_names),*> #observed_fn_pipe_name<Args, (#(#kind_type_names),*,)> for (#(#fn_type_names),*,)
             where #type_constraints
             {
                 #extra
 
                 #[inline]
-                extern "rust-call" fn #run(#ref_style self, args: Args) -> Self::Output {
-                    #call_impl
+                fn #run_observed<Obs: PipeObserver + ?Sized>(#ref_style self, observer: &Obs, args: Args) -> Self::Output {
+                    #stages_impl
+                    #last_output
                 }
             }
         }
@@ -132,24 +429,37 @@ pub fn impl_fn_pipe_for_tuple(item: TokenStream) -> TokenStream {
 
     // generic impl block parametrised on the number of params
     let impl_per_n = |n: usize| {
-        // create the `Fn/FnPipe` implementation
-        let mut impl_per_n =
-            impl_per_type_and_n(n, fn_pipe_names.0.clone(), None, Some(quote! { & }));
+        // create the `StageCount` implementation
+        let stage_types = &fn_type_names[0..n];
+        let count = Literal::usize_unsuffixed(n);
+        let mut impl_per_n = quote! {
+            impl<#(#stage_types),*> StageCount for (#(#stage_types),*,) {
+                const STAGE_COUNT: usize = #count;
+            }
+        };
 
-        // create the `FnMut/FnMutPipe` implementation
+        // create the `Fn/ObservedFnPipe` implementation
         impl_per_n.extend(impl_per_type_and_n(
             n,
-            fn_pipe_names.1.clone(),
+            observed_fn_pipe_names.0.clone(),
+            None,
+            Some(quote! { & }),
+        ));
+
+        // create the `FnMut/ObservedFnMutPipe` implementation
+        impl_per_n.extend(impl_per_type_and_n(
+            n,
+            observed_fn_pipe_names.1.clone(),
             None,
             Some(quote! { &mut }),
         ));
 
-        // create the `FnOnce/FnOncePipe` implementation;
+        // create the `FnOnce/ObservedFnOncePipe` implementation;
         // this implementation additionally needs to specify the associated `type Output`
-        let last = fn_type_names[n - 1].clone();
+        let last = &fn_type_names[n - 1];
         impl_per_n.extend(impl_per_type_and_n(
             n,
-            fn_pipe_names.2.clone(),
+            observed_fn_pipe_names.2.clone(),
             Some(quote! {
                 type Output = #last::Output;
             }),
@@ -169,6 +479,366 @@ pub fn impl_fn_pipe_for_tuple(item: TokenStream) -> TokenStream {
     impls.into()
 }
 
+/// Implements the tuple-manipulation traits backing `fn_pipe::Pipe` (i.e. `PushBack`, `PushFront`
+/// and `Concat`) for tuples of upto the given size, so pipes can be built up one stage at a time
+#[allow(
+    clippy::indexing_slicing,
+    clippy::arithmetic_side_effects,
+    clippy::missing_panics_doc
+)]
+#[proc_macro]
+pub fn impl_pipe_builder_for_tuple(item: TokenStream) -> TokenStream {
+    // get the maximum tuple size that a pipe can be built up to
+    let max_tuple_size = match parse_macro_input!(item as LitInt).base10_parse::<usize>() {
+        Ok(num) => num,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    assert!(
+        max_tuple_size > 0,
+        "passed parameter must be greater than zero"
+    );
+
+    // generate generic stage type-names and tuple field accessors, to be used later everywhere
+    let stage_type_names = (0..max_tuple_size)
+        .map(|i| format!("_{i}").parse_unchecked())
+        .collect::<Vec<_>>();
+    let field_access = |receiver: &str, range: std::ops::Range<usize>| {
+        range
+            .map(|i| format!("{receiver}.{i}").parse_unchecked())
+            .collect::<Vec<_>>()
+    };
+
+    let mut impls = TokS2::new();
+
+    // pushing a stage onto either end of a tuple of size `n` creates a tuple of size `n + 1`,
+    // so we need to implement for all tuple sizes 0 through-to `n - 1`
+    for n in 0..max_tuple_size {
+        let stages = &stage_type_names[0..n];
+        let fields = field_access("self", 0..n);
+
+        impls.extend(quote! {
+            impl<#(#stages,)* Stage> PushBack<Stage> for (#(#stages,)*) {
+                type Output = (#(#stages,)* Stage,);
+
+                #[inline]
+                fn push_back(self, stage: Stage) -> Self::Output {
+                    (#(#fields,)* stage,)
+                }
+            }
+
+            impl<#(#stages,)* Stage> PushFront<Stage> for (#(#stages,)*) {
+                type Output = (Stage, #(#stages,)*);
+
+                #[inline]
+                fn push_front(self, stage: Stage) -> Self::Output {
+                    (stage, #(#fields,)*)
+                }
+            }
+        });
+    }
+
+    // concatenating with the empty tuple is the base-case of the recursion
+    impls.extend(quote! {
+        impl<P> Concat<()> for P {
+            type Output = P;
+
+            #[inline]
+            fn concat(self, _other: ()) -> Self::Output {
+                self
+            }
+        }
+    });
+
+    // concatenating with a tuple of size `n` pushes its first stage onto the back, and then
+    // concatenates the remaining `n - 1` stages recursively
+    for n in 1..=max_tuple_size {
+        let stages = &stage_type_names[0..n];
+        let first = &stage_type_names[0];
+        let rest = &stage_type_names[1..n];
+        let first_field = field_access("other", 0..1);
+        let rest_fields = field_access("other", 1..n);
+
+        impls.extend(quote! {
+            impl<P, #(#stages,)*> Concat<(#(#stages,)*)> for P
+            where
+                P: PushBack<#first>,
+                <P as PushBack<#first>>::Output: Concat<(#(#rest,)*)>,
+            {
+                type Output = <<P as PushBack<#first>>::Output as Concat<(#(#rest,)*)>>::Output;
+
+                #[inline]
+                fn concat(self, other: (#(#stages,)*)) -> Self::Output {
+                    self.push_back(#(#first_field)*).concat((#(#rest_fields,)*))
+                }
+            }
+        });
+    }
+
+    // return all the impls
+    impls.into()
+}
+
+/// Implements the `Kleisli*Pipe` variants for tuples of upto the given size, where every stage
+/// returns a value of the same type constructor which is bound into the next stage
+#[allow(
+    clippy::indexing_slicing,
+    clippy::arithmetic_side_effects,
+    clippy::missing_panics_doc,
+    clippy::too_many_lines
+)]
+#[proc_macro]
+pub fn impl_kleisli_pipe_for_tuple(item: TokenStream) -> TokenStream {
+    // DEFINE CONSTANT TOKEN STREAMS UPFRONT
+    // token streams for Fn/Bind/Kleisli*Pipe variants
+    let kleisli_pipe_names = [
+        (
+            "Fn".parse_unchecked(),
+            "Bind".parse_unchecked(),
+            "KleisliPipe".parse_unchecked(),
+            "run_kleisli".parse_unchecked(),
+            "call".parse_unchecked(),
+            "bind".parse_unchecked(),
+            quote! { & },
+            None,
+        ),
+        (
+            "FnMut".parse_unchecked(),
+            "BindMut".parse_unchecked(),
+            "KleisliMutPipe".parse_unchecked(),
+            "run_kleisli_mut".parse_unchecked(),
+            "call_mut".parse_unchecked(),
+            "bind_mut".parse_unchecked(),
+            quote! { &mut },
+            None,
+        ),
+        (
+            "FnOnce".parse_unchecked(),
+            "BindOnce".parse_unchecked(),
+            "KleisliOncePipe".parse_unchecked(),
+            "run_kleisli_once".parse_unchecked(),
+            "call_once".parse_unchecked(),
+            "bind_once".parse_unchecked(),
+            TokS2::new(),
+            Some(quote! { move }),
+        ),
+    ];
+
+    // get the number of tuple parameters to implement this for
+    let max_tuple_size = match parse_macro_input!(item as LitInt).base10_parse::<usize>() {
+        Ok(num) => num,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    // generate generic stage type-names and the variable names of their outputs
+    let stage_type_names = (0..max_tuple_size)
+        .map(|i| format!("_{i}").parse_unchecked())
+        .collect::<Vec<_>>();
+    let output_vars = (0..max_tuple_size)
+        .map(|i| format!("x{i}").parse_unchecked())
+        .collect::<Vec<_>>();
+
+    let mut impls = TokS2::new();
+    for (fn_name, bind_name, pipe_name, run, call, bind, ref_style, move_style) in
+        kleisli_pipe_names
+    {
+        // the empty pipe is the identity, i.e. it simply lifts its input with `Pure::pure`
+        impls.extend(quote! {
+            impl<M: #bind_name + Pure, A> #pipe_name<M, A> for () {
+                type Output = A;
+
+                #[inline]
+                fn #run(#ref_style self, a: A) -> M::TC1<Self::Output> {
+                    M::pure(a)
+                }
+            }
+        });
+
+        for n in 1..=max_tuple_size {
+            let stages = &stage_type_names[0..n];
+            let last = stages[n - 1].clone();
+
+            // the input of the first stage is the pipe's input, and the input of every other stage
+            // is whatever the previous stage's output is constructed over
+            let stage_input = |i: usize| {
+                if i == 0 {
+                    quote! { A }
+                } else {
+                    let prev = stages[i - 1].clone();
+                    quote! { <#prev::Output as ConstructableTy1>::GenericParameter1 }
+                }
+            };
+            let type_constraints = (0..n).map(|i| {
+                let stage = stages[i].clone();
+                let input = stage_input(i);
+                quote! {
+                    #stage: #fn_name<(#input,)>,
+                    #stage::Output: ConstructableTy1<Constructor = M>
+                }
+            });
+
+            // call a stage with the output of the previous one, and reify its result so that it
+            // can be bound
+            let stage_call = |i: usize| {
+                let field = Literal::usize_unsuffixed(i);
+                let input = if i == 0 {
+                    quote! { a }
+                } else {
+                    output_vars[i - 1].clone()
+                };
+                quote! { self.#field.#call((#input,)).reify1() }
+            };
+
+            // nested bind implementation starting from the last stage
+            let mut run_impl = stage_call(n - 1);
+            for i in (0..n - 1).rev() {
+                let stage_call = stage_call(i);
+                let output_var = output_vars[i].clone();
+                run_impl = quote! {
+                    M::#bind(#stage_call, #move_style |#output_var| #run_impl)
+                };
+            }
+
+            impls.extend(quote! {
+                #[allow(clippy::type_repetition_in_bounds)]
+                impl<M: #bind_name, A, #(#stages),*> #pipe_name<M, A> for (#(#stages,)*)
+                where #(#type_constraints),*
+                {
+                    type Output = <#last::Output as ConstructableTy1>::GenericParameter1;
+
+                    #[inline]
+                    fn #run(#ref_style self, a: A) -> M::TC1<Self::Output> {
+                        #run_impl
+                    }
+                }
+            });
+        }
+    }
+
+    // return all the impls
+    impls.into()
+}
+
+/// Implements the `TupleFn*` traits (the stable-Rust stand-ins for the `Fn*` traits) for all
+/// closures taking upto the given number of arguments
+#[allow(clippy::missing_panics_doc)]
+#[proc_macro]
+pub fn impl_tuple_fn_for_closures(item: TokenStream) -> TokenStream {
+    // get the maximum number of arguments to implement this for
+    let max_arg_count = match parse_macro_input!(item as LitInt).base10_parse::<usize>() {
+        Ok(num) => num,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    // closures of zero arguments are also supported, so we need to implement for all argument
+    // counts 0 through-to `n`
+    let mut impls = TokS2::new();
+    for n in 0..=max_arg_count {
+        let arg_types = (0..n)
+            .map(|i| format!("_{i}").parse_unchecked())
+            .collect::<Vec<_>>();
+        let arg_fields = (0..n)
+            .map(|i| format!("args.{i}").parse_unchecked())
+            .collect::<Vec<_>>();
+
+        impls.extend(quote! {
+            impl<F, R, #(#arg_types,)*> TupleFnOnce<(#(#arg_types,)*)> for F
+            where F: FnOnce(#(#arg_types),*) -> R
+            {
+                type Output = R;
+
+                #[inline]
+                #[allow(unused_variables)]
+                fn call_tuple_once(self, args: (#(#arg_types,)*)) -> Self::Output {
+                    self(#(#arg_fields),*)
+                }
+            }
+
+            impl<F, R, #(#arg_types,)*> TupleFnMut<(#(#arg_types,)*)> for F
+            where F: FnMut(#(#arg_types),*) -> R
+            {
+                #[inline]
+                #[allow(unused_variables)]
+                fn call_tuple_mut(&mut self, args: (#(#arg_types,)*)) -> Self::Output {
+                    self(#(#arg_fields),*)
+                }
+            }
+
+            impl<F, R, #(#arg_types,)*> TupleFn<(#(#arg_types,)*)> for F
+            where F: Fn(#(#arg_types),*) -> R
+            {
+                #[inline]
+                #[allow(unused_variables)]
+                fn call_tuple(&self, args: (#(#arg_types,)*)) -> Self::Output {
+                    self(#(#arg_fields),*)
+                }
+            }
+        });
+    }
+
+    // return all the impls
+    impls.into()
+}
+
+/// Implements the conversions between tuples of upto the given size and their equivalent `HList`s
+/// (i.e. `IntoHList` and `IntoTuple`), so that pipes can be converted between both forms
+#[allow(clippy::missing_panics_doc)]
+#[proc_macro]
+pub fn impl_hlist_conversion_for_tuple(item: TokenStream) -> TokenStream {
+    // get the maximum tuple size to convert
+    let max_tuple_size = match parse_macro_input!(item as LitInt).base10_parse::<usize>() {
+        Ok(num) => num,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    // the empty tuple corresponds to the empty `HList`, so we need to implement for all tuple
+    // sizes 0 through-to `n`
+    let mut impls = TokS2::new();
+    for n in 0..=max_tuple_size {
+        let types = (0..n)
+            .map(|i| format!("_{i}").parse_unchecked())
+            .collect::<Vec<_>>();
+        let vars = (0..n)
+            .map(|i| format!("v{i}").parse_unchecked())
+            .collect::<Vec<_>>();
+
+        // build the nested `HCons` type and pattern from the inside out
+        let (mut hlist_type, mut hlist_pat) = (quote! { HNil }, quote! { HNil });
+        for (ty, var) in types.iter().zip(&vars).rev() {
+            hlist_type = quote! { HCons<#ty, #hlist_type> };
+            hlist_pat = quote! { HCons { head: #var, tail: #hlist_pat } };
+        }
+
+        // the empty tuple is just a unit expression, which clippy would rather see left out
+        let allow_unit = (n == 0).then(|| quote! { #[allow(clippy::unused_unit)] });
+
+        impls.extend(quote! {
+            impl<#(#types),*> IntoHList for (#(#types,)*) {
+                type Output = #hlist_type;
+
+                #[inline]
+                fn into_hlist(self) -> Self::Output {
+                    let (#(#vars,)*) = self;
+                    #hlist_pat
+                }
+            }
+
+            impl<#(#types),*> IntoTuple for #hlist_type {
+                type Output = (#(#types,)*);
+
+                #[inline]
+                #allow_unit
+                fn into_tuple(self) -> Self::Output {
+                    let #hlist_pat = self;
+                    (#(#vars,)*)
+                }
+            }
+        });
+    }
+
+    // return all the impls
+    impls.into()
+}
+
 #[ext]
 impl [TokS2] {
     #[allow(clippy::unwrap_used, clippy::single_call_fn)]
@@ -0,0 +1,291 @@
+//! Adapters for the input and output types of existing pipes.
+//!
+//! They let a pipe be reused where slightly different types are needed, without wrapping it in a
+//! closure (which would lose the `Fn/Pipe*` variants).
+//!
+//! Each adapter keeps the exact `Fn/Pipe*` capability of the pipe and functions it was made from,
+//! and is itself callable as an ordinary closure with that same capability.
+
+use crate::{FnMutPipe, FnOncePipe, FnPipe};
+use std::marker::Tuple;
+
+/// Maps the output of a pipe with a function, i.e. runs the pipe and then the function.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct MapOutput<P, F>(pub P, pub F);
+
+/// Maps the input of a pipe with a function, i.e. runs the function and then the pipe.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct ContramapInput<G, P>(pub G, pub P);
+
+/// Maps both the input and the output of a pipe, i.e. runs the first function, then the pipe and
+/// then the second function.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Dimap<G, P, F>(pub G, pub P, pub F);
+
+/// An extension trait providing the input/output adapters on every pipe.
+///
+/// The functions are deliberately left unbounded here, so that closures passed in keep the
+/// strongest `Fn*` capability their bodies allow rather than being inferred as `FnOnce`.
+pub trait PipeExt<Args: Tuple>: FnOncePipe<Args> + Sized {
+    /// Maps the output of this pipe with `f`, see [`MapOutput`].
+    #[inline]
+    fn map_output<F>(self, f: F) -> MapOutput<Self, F> {
+        MapOutput(self, f)
+    }
+
+    /// Maps the input of this pipe with `g`, whose output becomes the argument of this pipe, see
+    /// [`ContramapInput`].
+    #[inline]
+    fn contramap_input<G>(self, g: G) -> ContramapInput<G, Self> {
+        ContramapInput(g, self)
+    }
+
+    /// Maps the input of this pipe with `g` and its output with `f`, see [`Dimap`].
+    #[inline]
+    fn dimap<G, F>(self, g: G, f: F) -> Dimap<G, Self, F> {
+        Dimap(g, self, f)
+    }
+}
+
+impl<Args: Tuple, P: FnOncePipe<Args>> PipeExt<Args> for P {}
+
+// every adapted pipe is also callable as an ordinary closure, so that it can be nested in other pipes
+macro_rules! impl_fn_via_pipe {
+    ($adapter:ident<$($param:ident),+>) => {
+        impl<Args: Tuple, $($param),+> FnOnce<Args> for $adapter<$($param),+>
+        where
+            Self: FnOncePipe<Args>,
+        {
+            type Output = <Self as FnOncePipe<Args>>::Output;
+
+            #[inline]
+            extern "rust-call" fn call_once(self, args: Args) -> Self::Output {
+                self.run_once(args)
+            }
+        }
+
+        impl<Args: Tuple, $($param),+> FnMut<Args> for $adapter<$($param),+>
+        where
+            Self: FnMutPipe<Args>,
+        {
+            #[inline]
+            extern "rust-call" fn call_mut(&mut self, args: Args) -> Self::Output {
+                self.run_mut(args)
+            }
+        }
+
+        impl<Args: Tuple, $($param),+> Fn<Args> for $adapter<$($param),+>
+        where
+            Self: FnPipe<Args>,
+        {
+            #[inline]
+            extern "rust-call" fn call(&self, args: Args) -> Self::Output {
+                self.run(args)
+            }
+        }
+    };
+}
+
+impl_fn_via_pipe!(MapOutput<P, F>);
+impl_fn_via_pipe!(ContramapInput<G, P>);
+impl_fn_via_pipe!(Dimap<G, P, F>);
+
+mod map_output_impls {
+    use crate::adapt::MapOutput;
+    use crate::{FnMutPipe, FnOncePipe, FnPipe};
+    use std::marker::Tuple;
+
+    impl<Args: Tuple, P: FnOncePipe<Args>, F: FnOnce<(P::Output,)>> FnOncePipe<Args>
+        for MapOutput<P, F>
+    {
+        type Output = F::Output;
+
+        #[inline]
+        extern "rust-call" fn run_once(self, args: Args) -> Self::Output {
+            self.1.call_once((self.0.run_once(args),))
+        }
+    }
+
+    impl<Args: Tuple, P: FnMutPipe<Args>, F: FnMut<(P::Output,)>> FnMutPipe<Args> for MapOutput<P, F> {
+        #[inline]
+        extern "rust-call" fn run_mut(&mut self, args: Args) -> Self::Output {
+            self.1.call_mut((self.0.run_mut(args),))
+        }
+    }
+
+    impl<Args: Tuple, P: FnPipe<Args>, F: Fn<(P::Output,)>> FnPipe<Args> for MapOutput<P, F> {
+        #[inline]
+        extern "rust-call" fn run(&self, args: Args) -> Self::Output {
+            self.1.call((self.0.run(args),))
+        }
+    }
+}
+
+mod contramap_input_impls {
+    use crate::adapt::ContramapInput;
+    use crate::{FnMutPipe, FnOncePipe, FnPipe};
+    use std::marker::Tuple;
+
+    impl<Args: Tuple, G: FnOnce<Args>, P: FnOncePipe<(G::Output,)>> FnOncePipe<Args>
+        for ContramapInput<G, P>
+    {
+        type Output = P::Output;
+
+        #[inline]
+        extern "rust-call" fn run_once(self, args: Args) -> Self::Output {
+            self.1.run_once((self.0.call_once(args),))
+        }
+    }
+
+    impl<Args: Tuple, G: FnMut<Args>, P: FnMutPipe<(G::Output,)>> FnMutPipe<Args>
+        for ContramapInput<G, P>
+    {
+        #[inline]
+        extern "rust-call" fn run_mut(&mut self, args: Args) -> Self::Output {
+            self.1.run_mut((self.0.call_mut(args),))
+        }
+    }
+
+    impl<Args: Tuple, G: Fn<Args>, P: FnPipe<(G::Output,)>> FnPipe<Args> for ContramapInput<G, P> {
+        #[inline]
+        extern "rust-call" fn run(&self, args: Args) -> Self::Output {
+            self.1.run((self.0.call(args),))
+        }
+    }
+}
+
+mod dimap_impls {
+    use crate::adapt::Dimap;
+    use crate::{FnMutPipe, FnOncePipe, FnPipe};
+    use std::marker::Tuple;
+
+    impl<Args, G, P, F> FnOncePipe<Args> for Dimap<G, P, F>
+    where
+        Args: Tuple,
+        G: FnOnce<Args>,
+        P: FnOncePipe<(G::Output,)>,
+        F: FnOnce<(P::Output,)>,
+    {
+        type Output = F::Output;
+
+        #[inline]
+        extern "rust-call" fn run_once(self, args: Args) -> Self::Output {
+            self.2
+                .call_once((self.1.run_once((self.0.call_once(args),)),))
+        }
+    }
+
+    impl<Args, G, P, F> FnMutPipe<Args> for Dimap<G, P, F>
+    where
+        Args: Tuple,
+        G: FnMut<Args>,
+        P: FnMutPipe<(G::Output,)>,
+        F: FnMut<(P::Output,)>,
+    {
+        #[inline]
+        extern "rust-call" fn run_mut(&mut self, args: Args) -> Self::Output {
+            self.2.call_mut((self.1.run_mut((self.0.call_mut(args),)),))
+        }
+    }
+
+    impl<Args, G, P, F> FnPipe<Args> for Dimap<G, P, F>
+    where
+        Args: Tuple,
+        G: Fn<Args>,
+        P: FnPipe<(G::Output,)>,
+        F: Fn<(P::Output,)>,
+    {
+        #[inline]
+        extern "rust-call" fn run(&self, args: Args) -> Self::Output {
+            self.2.call((self.1.run((self.0.call(args),)),))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapt::PipeExt as _;
+    use crate::{FnMutPipe as _, FnOncePipe as _, FnPipe as _};
+
+    /// Calls the function with both arguments, through the [`Fn`] trait.
+    fn call_fn<F: Fn(u32) -> u32>(f: &F, x: u32, y: u32) -> (u32, u32) {
+        (f(x), f(y))
+    }
+
+    /// Calls the function with both arguments, through the [`FnMut`] trait.
+    fn call_fn_mut<F: FnMut(u32) -> u32>(mut f: F, x: u32, y: u32) -> (u32, u32) {
+        (f(x), f(y))
+    }
+
+    /// Calls the function with the argument, through the [`FnOnce`] trait.
+    fn call_fn_once<A, B, F: FnOnce(A) -> B>(f: F, a: A) -> B {
+        f(a)
+    }
+
+    #[test]
+    fn map_output_runs_after() {
+        let pipe = (|n: u32| n.saturating_add(1),).map_output(|n: u32| n.saturating_mul(10));
+        assert_eq!(pipe.run((1,)), 20);
+        assert_eq!(call_fn(&pipe, 2, 3), (30, 40));
+    }
+
+    #[test]
+    fn contramap_input_runs_before() {
+        let pipe = (|n: u32| n.saturating_add(1),).contramap_input(|n: u32| n.saturating_mul(10));
+        assert_eq!(pipe.run((1,)), 11);
+        assert_eq!(call_fn(&pipe, 2, 3), (21, 31));
+    }
+
+    #[test]
+    fn dimap_runs_around() {
+        let pipe = (|s: String| s.len(),).dimap(
+            |n: u32| "x".repeat(n.try_into().unwrap_or_default()),
+            |len: usize| u32::try_from(len).unwrap_or_default().saturating_mul(2),
+        );
+        assert_eq!(pipe.run((3,)), 6);
+        assert_eq!(call_fn(&pipe, 0, 1), (0, 2));
+    }
+
+    #[test]
+    fn keeps_fn_mut() {
+        let mut calls = 0_u32;
+        let mut pipe = (|n: u32| {
+            calls = calls.saturating_add(1);
+            n
+        },)
+            .map_output(|n: u32| n.saturating_add(1))
+            .contramap_input(|n: u32| n.saturating_mul(2));
+        assert_eq!(pipe.run_mut((1,)), 3);
+        assert_eq!(call_fn_mut(&mut pipe, 2, 3), (5, 7));
+        let mut mapped = 0_u32;
+        let dimapped = pipe.dimap(
+            |n: u32| n,
+            |n: u32| {
+                mapped = mapped.saturating_add(1);
+                n
+            },
+        );
+        assert_eq!(call_fn_mut(dimapped, 4, 5), (9, 11));
+        assert_eq!((calls, mapped), (5, 2));
+    }
+
+    #[test]
+    fn keeps_fn_once() {
+        // both closures consume what they captured, so can only be called once
+        let suffix = "!".to_owned();
+        let pipe = (move |s: String| [s, suffix].concat(),)
+            .map_output(|s: String| s.to_uppercase())
+            .contramap_input(|n: u32| n.to_string());
+        assert_eq!(pipe.run_once((1,)), "1!");
+        let tail = b"?".to_vec();
+        let dimapped = (|s: String| s,).dimap(
+            |s: String| s,
+            move |s: String| {
+                let mut bytes = s.into_bytes();
+                bytes.extend(tail);
+                bytes
+            },
+        );
+        assert_eq!(call_fn_once(dimapped, "ok".to_owned()), b"ok?");
+    }
+}
@@ -0,0 +1,205 @@
+//! Arrow-style combinators for branching dataflows.
+//!
+//! Each of them is itself an `Fn*` implementor, so they can be used as stages of any pipe, and
+//! pipes can be used as their branches via [`Piped`](crate::Piped).
+
+/// Feeds one (cloned) input to both branches, producing the pair of their outputs.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Fanout<F, G>(pub F, pub G);
+
+/// Maps a pair by running each branch on its corresponding half.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Split<F, G>(pub F, pub G);
+
+/// Maps the first half of a pair, passing the second half through untouched.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[repr(transparent)]
+pub struct First<F>(pub F);
+
+/// Maps the second half of a pair, passing the first half through untouched.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[repr(transparent)]
+pub struct Second<F>(pub F);
+
+/// Creates a stage that maps `x` to `(f(x), g(x))`, see [`Fanout`].
+#[inline]
+pub fn fanout<F, G>((f, g): (F, G)) -> Fanout<F, G> {
+    Fanout(f, g)
+}
+
+/// Creates a stage that maps `(a, b)` to `(f(a), g(b))`, see [`Split`].
+#[inline]
+pub fn split<F, G>((f, g): (F, G)) -> Split<F, G> {
+    Split(f, g)
+}
+
+/// Creates a stage that maps `(a, c)` to `(f(a), c)`, see [`First`].
+#[inline]
+pub const fn first<F>(f: F) -> First<F> {
+    First(f)
+}
+
+/// Creates a stage that maps `(c, a)` to `(c, f(a))`, see [`Second`].
+#[inline]
+pub const fn second<F>(f: F) -> Second<F> {
+    Second(f)
+}
+
+mod fanout_impls {
+    use crate::arrow::Fanout;
+
+    impl<A: Clone, F: FnOnce<(A,)>, G: FnOnce<(A,)>> FnOnce<(A,)> for Fanout<F, G> {
+        type Output = (F::Output, G::Output);
+
+        #[inline]
+        extern "rust-call" fn call_once(self, args: (A,)) -> Self::Output {
+            (self.0.call_once((args.0.clone(),)), self.1.call_once(args))
+        }
+    }
+
+    impl<A: Clone, F: FnMut<(A,)>, G: FnMut<(A,)>> FnMut<(A,)> for Fanout<F, G> {
+        #[inline]
+        extern "rust-call" fn call_mut(&mut self, args: (A,)) -> Self::Output {
+            (self.0.call_mut((args.0.clone(),)), self.1.call_mut(args))
+        }
+    }
+
+    impl<A: Clone, F: Fn<(A,)>, G: Fn<(A,)>> Fn<(A,)> for Fanout<F, G> {
+        #[inline]
+        extern "rust-call" fn call(&self, args: (A,)) -> Self::Output {
+            (self.0.call((args.0.clone(),)), self.1.call(args))
+        }
+    }
+}
+
+mod split_impls {
+    use crate::arrow::Split;
+
+    impl<A, B, F: FnOnce<(A,)>, G: FnOnce<(B,)>> FnOnce<((A, B),)> for Split<F, G> {
+        type Output = (F::Output, G::Output);
+
+        #[inline]
+        extern "rust-call" fn call_once(self, args: ((A, B),)) -> Self::Output {
+            let (a, b) = args.0;
+            (self.0.call_once((a,)), self.1.call_once((b,)))
+        }
+    }
+
+    impl<A, B, F: FnMut<(A,)>, G: FnMut<(B,)>> FnMut<((A, B),)> for Split<F, G> {
+        #[inline]
+        extern "rust-call" fn call_mut(&mut self, args: ((A, B),)) -> Self::Output {
+            let (a, b) = args.0;
+            (self.0.call_mut((a,)), self.1.call_mut((b,)))
+        }
+    }
+
+    impl<A, B, F: Fn<(A,)>, G: Fn<(B,)>> Fn<((A, B),)> for Split<F, G> {
+        #[inline]
+        extern "rust-call" fn call(&self, args: ((A, B),)) -> Self::Output {
+            let (a, b) = args.0;
+            (self.0.call((a,)), self.1.call((b,)))
+        }
+    }
+}
+
+mod first_impls {
+    use crate::arrow::First;
+
+    impl<A, C, F: FnOnce<(A,)>> FnOnce<((A, C),)> for First<F> {
+        type Output = (F::Output, C);
+
+        #[inline]
+        extern "rust-call" fn call_once(self, args: ((A, C),)) -> Self::Output {
+            let (a, c) = args.0;
+            (self.0.call_once((a,)), c)
+        }
+    }
+
+    impl<A, C, F: FnMut<(A,)>> FnMut<((A, C),)> for First<F> {
+        #[inline]
+        extern "rust-call" fn call_mut(&mut self, args: ((A, C),)) -> Self::Output {
+            let (a, c) = args.0;
+            (self.0.call_mut((a,)), c)
+        }
+    }
+
+    impl<A, C, F: Fn<(A,)>> Fn<((A, C),)> for First<F> {
+        #[inline]
+        extern "rust-call" fn call(&self, args: ((A, C),)) -> Self::Output {
+            let (a, c) = args.0;
+            (self.0.call((a,)), c)
+        }
+    }
+}
+
+mod second_impls {
+    use crate::arrow::Second;
+
+    impl<A, C, F: FnOnce<(A,)>> FnOnce<((C, A),)> for Second<F> {
+        type Output = (C, F::Output);
+
+        #[inline]
+        extern "rust-call" fn call_once(self, args: ((C, A),)) -> Self::Output {
+            let (c, a) = args.0;
+            (c, self.0.call_once((a,)))
+        }
+    }
+
+    impl<A, C, F: FnMut<(A,)>> FnMut<((C, A),)> for Second<F> {
+        #[inline]
+        extern "rust-call" fn call_mut(&mut self, args: ((C, A),)) -> Self::Output {
+            let (c, a) = args.0;
+            (c, self.0.call_mut((a,)))
+        }
+    }
+
+    impl<A, C, F: Fn<(A,)>> Fn<((C, A),)> for Second<F> {
+        #[inline]
+        extern "rust-call" fn call(&self, args: ((C, A),)) -> Self::Output {
+            let (c, a) = args.0;
+            (c, self.0.call((a,)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::arrow::{fanout, first, second, split};
+    use crate::{FnMutPipe as _, FnPipe as _};
+
+    #[test]
+    fn fanout_feeds_both_branches() {
+        let pipe = (
+            |s: &str| s.to_owned(),
+            fanout((|s: String| s.len(), |s: String| s.to_uppercase())),
+        );
+        assert_eq!(pipe.run(("abc",)), (3, "ABC".to_owned()));
+    }
+
+    #[test]
+    fn split_maps_each_half() {
+        let pipe = (split((|n: u32| n.saturating_add(1), |s: &str| s.len())),);
+        assert_eq!(pipe.run(((1, "abc"),)), (2, 3));
+    }
+
+    #[test]
+    fn first_maps_first_half() {
+        let mut calls = 0_u32;
+        let mut pipe = (first(|n: u32| {
+            calls = calls.saturating_add(1);
+            n.to_string()
+        }),);
+        assert_eq!(pipe.run_mut(((1, 'c'),)), ("1".to_owned(), 'c'));
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn second_maps_second_half() {
+        let pipe = (
+            |n: u32| (n, n),
+            second(|n: u32| n.saturating_mul(10)),
+            first(|n: u32| n.saturating_sub(1)),
+        );
+        assert_eq!(pipe.run((2,)), (1, 20));
+    }
+}
@@ -0,0 +1,107 @@
+//! Asynchronous pipes, where every stage produces a [`Future`] which is awaited before its output
+//! is fed into the next stage. Nothing here depends on any particular executor.
+
+use fn_pipe_proc::impl_async_fn_pipe_for_tuple;
+use std::marker::Tuple;
+
+/// A trait representing a pipe of asynchronous functions, where the awaited output of one will
+/// be fed as the input of another, until the entire pipe ran
+pub trait AsyncFnPipe<Args: Tuple>: AsyncFnMutPipe<Args> {
+    extern "rust-call" fn async_run(&self, args: Args) -> impl Future<Output = Self::Output>;
+}
+
+pub trait AsyncFnMutPipe<Args: Tuple>: AsyncFnOncePipe<Args> {
+    extern "rust-call" fn async_run_mut(
+        &mut self,
+        args: Args,
+    ) -> impl Future<Output = Self::Output>;
+}
+
+pub trait AsyncFnOncePipe<Args: Tuple> {
+    /// The awaited output of the last stage.
+    type Output;
+
+    extern "rust-call" fn async_run_once(self, args: Args) -> impl Future<Output = Self::Output>;
+}
+
+// implement `AsyncFn/Pipe*` variants for tuples of upto length 26, to match the `Fn/Pipe*` variants
+impl_async_fn_pipe_for_tuple!(26usize);
+
+#[cfg(test)]
+mod tests {
+    use crate::{AsyncFnMutPipe as _, AsyncFnOncePipe as _, AsyncFnPipe as _, pipe};
+    use std::pin::pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread::{self, Thread};
+
+    /// Wakes up the thread which is blocked on a future.
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    /// A minimal executor, which just parks the current thread until the future can make progress.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    /// A future which is pending once before completing, to make sure the waker is hooked up.
+    async fn yield_now() {
+        let mut yielded = false;
+        std::future::poll_fn(|cx| {
+            if yielded {
+                Poll::Ready(())
+            } else {
+                yielded = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        })
+        .await;
+    }
+
+    async fn parse(s: &str) -> u32 {
+        yield_now().await;
+        s.parse().unwrap_or_default()
+    }
+
+    async fn double(x: u32) -> u32 {
+        yield_now().await;
+        x.saturating_mul(2)
+    }
+
+    #[test]
+    fn awaits_every_stage() {
+        let pipe = (parse, double, async |x: u32| x.to_string());
+        assert_eq!(block_on(pipe.async_run(("21",))), "42");
+        assert_eq!(block_on(pipe.async_run(("4",))), "8");
+    }
+
+    #[test]
+    fn mut_and_once_stages() {
+        let mut calls = 0;
+        let mut counting = (double, |x: u32| {
+            calls += 1;
+            async move { x.saturating_add(1) }
+        });
+        assert_eq!(block_on(counting.async_run_mut((1,))), 3);
+        assert_eq!(block_on(counting.async_run_mut((2,))), 5);
+        assert_eq!(calls, 2);
+
+        let suffix = String::from("!");
+        let suffixing = pipe![parse, async move |x: u32| format!("{x}{suffix}")];
+        assert_eq!(block_on(suffixing.async_run_once(("7",))), "7!");
+    }
+}
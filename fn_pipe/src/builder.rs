@@ -0,0 +1,348 @@
+//! A fluent builder for pipes, so that they can be put together one stage at a time rather than
+//! having to write out the entire tuple in one go.
+
+#[cfg(feature = "stable")]
+use crate::stable;
+#[cfg(feature = "nightly")]
+use crate::{
+    AsyncFnMutPipe, AsyncFnOncePipe, AsyncFnPipe, FnMutPipe, FnOncePipe, FnPipe, Instrumented,
+    StageCount, TryFnMutPipe, TryFnOncePipe, TryFnPipe,
+};
+use fn_pipe_proc::impl_pipe_builder_for_tuple;
+#[cfg(feature = "nightly")]
+use std::marker::Tuple;
+
+/// A trait for tuples that can have a stage pushed onto their back.
+pub trait PushBack<Stage> {
+    type Output;
+
+    fn push_back(self, stage: Stage) -> Self::Output;
+}
+
+/// A trait for tuples that can have a stage pushed onto their front.
+pub trait PushFront<Stage> {
+    type Output;
+
+    fn push_front(self, stage: Stage) -> Self::Output;
+}
+
+/// A trait for tuples that can have all the stages of another tuple concatenated onto their back.
+pub trait Concat<Other> {
+    type Output;
+
+    fn concat(self, other: Other) -> Self::Output;
+}
+
+/// A builder-wrapper around a tuple pipe, which lets stages be added to either end of it and lets
+/// entire pipes be spliced together, all while keeping track of the resulting tuple type.
+///
+/// It is itself a pipe, running exactly as the wrapped tuple would.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[repr(transparent)]
+pub struct Pipe<P>(P);
+
+/// Builds a [`Pipe`] out of the given stages, in order.
+#[macro_export]
+macro_rules! pipe {
+    () => {
+        $crate::builder::Pipe::new()
+    };
+    ($($stage:expr),+ $(,)?) => {
+        $crate::builder::Pipe::from_tuple(($($stage,)+))
+    };
+}
+
+impl Pipe<()> {
+    /// Creates an empty pipe, with no stages yet.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(())
+    }
+}
+
+impl<P> Pipe<P> {
+    /// Wraps an existing tuple pipe.
+    #[inline]
+    pub const fn from_tuple(pipe: P) -> Self {
+        Self(pipe)
+    }
+
+    /// Unwraps the underlying tuple pipe.
+    #[inline]
+    pub fn into_tuple(self) -> P {
+        self.0
+    }
+
+    /// Adds a stage to the end of this pipe, so that it runs after all the others.
+    #[inline]
+    pub fn then<Stage>(self, stage: Stage) -> Pipe<P::Output>
+    where
+        P: PushBack<Stage>,
+    {
+        Pipe(self.0.push_back(stage))
+    }
+
+    /// Adds a stage to the start of this pipe, so that it runs before all the others.
+    #[inline]
+    pub fn prepend<Stage>(self, stage: Stage) -> Pipe<P::Output>
+    where
+        P: PushFront<Stage>,
+    {
+        Pipe(self.0.push_front(stage))
+    }
+
+    /// Adds all the stages of another pipe to the end of this pipe.
+    #[inline]
+    pub fn append<Q>(self, other: Pipe<Q>) -> Pipe<P::Output>
+    where
+        P: Concat<Q>,
+    {
+        Pipe(self.0.concat(other.0))
+    }
+
+    /// Instruments this pipe, so that it reports the progress of its stages to the observer.
+    ///
+    /// Only the stages wrapped in [`Debugged`](crate::Debugged) and the like report views of
+    /// their values, see [`instrumented!`](crate::instrumented) for wrapping them automatically.
+    #[cfg(feature = "nightly")]
+    #[inline]
+    pub fn instrument<O, Kinds>(self, observer: O) -> Instrumented<P, O, Kinds> {
+        Instrumented::new(self.0, observer)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<P: StageCount> StageCount for Pipe<P> {
+    const STAGE_COUNT: usize = P::STAGE_COUNT;
+}
+
+impl<P> From<P> for Pipe<P> {
+    #[inline]
+    fn from(pipe: P) -> Self {
+        Self(pipe)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<Args: Tuple, P: FnPipe<Args>> FnPipe<Args> for Pipe<P> {
+    #[inline]
+    extern "rust-call" fn run(&self, args: Args) -> Self::Output {
+        self.0.run(args)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<Args: Tuple, P: FnMutPipe<Args>> FnMutPipe<Args> for Pipe<P> {
+    #[inline]
+    extern "rust-call" fn run_mut(&mut self, args: Args) -> Self::Output {
+        self.0.run_mut(args)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<Args: Tuple, P: FnOncePipe<Args>> FnOncePipe<Args> for Pipe<P> {
+    type Output = P::Output;
+
+    #[inline]
+    extern "rust-call" fn run_once(self, args: Args) -> Self::Output {
+        self.0.run_once(args)
+    }
+}
+
+// a built pipe is also callable as an ordinary closure, so that it can be nested in other pipes
+#[cfg(feature = "nightly")]
+impl<Args: Tuple, P: FnOncePipe<Args>> FnOnce<Args> for Pipe<P> {
+    type Output = P::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: Args) -> Self::Output {
+        self.0.run_once(args)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<Args: Tuple, P: FnMutPipe<Args>> FnMut<Args> for Pipe<P> {
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: Args) -> Self::Output {
+        self.0.run_mut(args)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<Args: Tuple, P: FnPipe<Args>> Fn<Args> for Pipe<P> {
+    #[inline]
+    extern "rust-call" fn call(&self, args: Args) -> Self::Output {
+        self.0.run(args)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<Args: Tuple, P: TryFnPipe<Args>> TryFnPipe<Args> for Pipe<P> {
+    #[inline]
+    extern "rust-call" fn try_run(&self, args: Args) -> Self::Output {
+        self.0.try_run(args)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<Args: Tuple, P: TryFnMutPipe<Args>> TryFnMutPipe<Args> for Pipe<P> {
+    #[inline]
+    extern "rust-call" fn try_run_mut(&mut self, args: Args) -> Self::Output {
+        self.0.try_run_mut(args)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<Args: Tuple, P: TryFnOncePipe<Args>> TryFnOncePipe<Args> for Pipe<P> {
+    type Output = P::Output;
+
+    #[inline]
+    extern "rust-call" fn try_run_once(self, args: Args) -> Self::Output {
+        self.0.try_run_once(args)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<Args: Tuple, P: AsyncFnPipe<Args>> AsyncFnPipe<Args> for Pipe<P> {
+    #[inline]
+    extern "rust-call" fn async_run(&self, args: Args) -> impl Future<Output = Self::Output> {
+        self.0.async_run(args)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<Args: Tuple, P: AsyncFnMutPipe<Args>> AsyncFnMutPipe<Args> for Pipe<P> {
+    #[inline]
+    extern "rust-call" fn async_run_mut(
+        &mut self,
+        args: Args,
+    ) -> impl Future<Output = Self::Output> {
+        self.0.async_run_mut(args)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<Args: Tuple, P: AsyncFnOncePipe<Args>> AsyncFnOncePipe<Args> for Pipe<P> {
+    type Output = P::Output;
+
+    #[inline]
+    extern "rust-call" fn async_run_once(self, args: Args) -> impl Future<Output = Self::Output> {
+        self.0.async_run_once(args)
+    }
+}
+
+#[cfg(feature = "stable")]
+impl<Args, P: stable::FnPipe<Args>> stable::FnPipe<Args> for Pipe<P> {
+    #[inline]
+    fn run(&self, args: Args) -> Self::Output {
+        self.0.run(args)
+    }
+}
+
+#[cfg(feature = "stable")]
+impl<Args, P: stable::FnMutPipe<Args>> stable::FnMutPipe<Args> for Pipe<P> {
+    #[inline]
+    fn run_mut(&mut self, args: Args) -> Self::Output {
+        self.0.run_mut(args)
+    }
+}
+
+#[cfg(feature = "stable")]
+impl<Args, P: stable::FnOncePipe<Args>> stable::FnOncePipe<Args> for Pipe<P> {
+    type Output = P::Output;
+
+    #[inline]
+    fn run_once(self, args: Args) -> Self::Output {
+        self.0.run_once(args)
+    }
+}
+
+// a built pipe is also usable as an ordinary stage, so that it can be nested in other pipes; with
+// the `nightly` flavour it is a closure, and so already gets the `TupleFn*` closure implementations
+#[cfg(all(feature = "stable", not(feature = "nightly")))]
+impl<Args, P: stable::FnOncePipe<Args>> stable::TupleFnOnce<Args> for Pipe<P> {
+    type Output = P::Output;
+
+    #[inline]
+    fn call_tuple_once(self, args: Args) -> Self::Output {
+        self.0.run_once(args)
+    }
+}
+
+#[cfg(all(feature = "stable", not(feature = "nightly")))]
+impl<Args, P: stable::FnMutPipe<Args>> stable::TupleFnMut<Args> for Pipe<P> {
+    #[inline]
+    fn call_tuple_mut(&mut self, args: Args) -> Self::Output {
+        self.0.run_mut(args)
+    }
+}
+
+#[cfg(all(feature = "stable", not(feature = "nightly")))]
+impl<Args, P: stable::FnPipe<Args>> stable::TupleFn<Args> for Pipe<P> {
+    #[inline]
+    fn call_tuple(&self, args: Args) -> Self::Output {
+        self.0.run(args)
+    }
+}
+
+// implement the building traits for tuples of upto length 26, to match the `Fn/Pipe*` variants
+impl_pipe_builder_for_tuple!(26usize);
+
+#[cfg(test)]
+mod tests {
+    use crate::builder::Pipe;
+    #[cfg(all(feature = "stable", not(feature = "nightly")))]
+    use crate::stable::{FnMutPipe as _, FnOncePipe as _, FnPipe as _};
+    #[cfg(feature = "nightly")]
+    use crate::{FnMutPipe as _, FnOncePipe as _, FnPipe as _};
+
+    /// A stage that records its own name after everything recorded by the stages before it.
+    fn stage(name: &'static str) -> impl Fn(String) -> String + Copy {
+        move |trace| format!("{trace}{name}")
+    }
+
+    #[test]
+    fn then_runs_stages_in_order() {
+        let pipe = Pipe::new()
+            .then(stage("a"))
+            .then(stage("b"))
+            .then(stage("c"));
+        assert_eq!(pipe.run((String::new(),)), "abc");
+    }
+
+    #[test]
+    fn prepend_runs_stages_in_reverse_order() {
+        let pipe = Pipe::new()
+            .prepend(stage("a"))
+            .prepend(stage("b"))
+            .prepend(stage("c"));
+        assert_eq!(pipe.run((String::new(),)), "cba");
+    }
+
+    #[test]
+    fn append_runs_other_pipe_after() {
+        let front = Pipe::new().then(stage("a")).then(stage("b"));
+        let back = Pipe::new().then(stage("c")).then(stage("d"));
+        assert_eq!(front.append(back).run((String::new(),)), "abcd");
+        assert_eq!(back.append(front).run((String::new(),)), "cdab");
+        assert_eq!(front.append(Pipe::new()).run((String::new(),)), "ab");
+    }
+
+    #[test]
+    fn macro_runs_stages_in_order() {
+        let mut calls = Vec::new();
+        let mut pipe = pipe![
+            stage("a"),
+            |trace: String| {
+                calls.push(trace.clone());
+                trace
+            },
+            stage("b"),
+            stage("c"),
+        ];
+        assert_eq!(pipe.run_mut((String::new(),)), "abc");
+        assert_eq!(pipe.run_once(("x".to_owned(),)), "xabc");
+        assert_eq!(calls, ["a", "xa"]);
+    }
+}
@@ -0,0 +1,435 @@
+//! Type-erased pipes which are assembled at runtime, e.g. from configuration, rather than being
+//! fixed at compile time like the tuple pipes.
+//!
+//! Every stage is checked against the previous one (by [`TypeId`]) as it is pushed, so mismatched
+//! stages are reported as a [`DynPipeError`] rather than causing a panic when the pipe runs.
+
+use std::any::{Any, TypeId, type_name};
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use thiserror::Error;
+
+/// The runtime identity of a type, along with its name for the sake of error messages.
+#[derive(Copy, Clone, Debug)]
+struct TypeInfo {
+    id: TypeId,
+    name: &'static str,
+}
+
+impl TypeInfo {
+    #[inline]
+    fn of<T: 'static>() -> Self {
+        Self {
+            id: TypeId::of::<T>(),
+            name: type_name::<T>(),
+        }
+    }
+}
+
+/// A type-erased function, which yields nothing if it was given an input of the wrong type.
+type ErasedFn = dyn Fn(Box<dyn Any>) -> Option<Box<dyn Any>>;
+
+/// An error that occurs while assembling or running a [`DynPipe`].
+#[derive(Clone, PartialEq, Eq, Debug, Error)]
+pub enum DynPipeError {
+    /// A stage was pushed whose input doesn't match the output of the stage before it.
+    #[error(
+        "stage {index}{} expects an input of type `{expected}`, but {} is of type `{found}`",
+        name.as_ref().map(|name| format!(" (`{name}`)")).unwrap_or_default(),
+        if *index == 0 { "the pipe's input" } else { "the previous stage's output" }
+    )]
+    StageMismatch {
+        /// The position the stage would have had within the pipe.
+        index: usize,
+        /// The name of the stage, if it has one.
+        name: Option<String>,
+        /// The type of input the stage expects.
+        expected: &'static str,
+        /// The type of output the previous stage produces.
+        found: &'static str,
+    },
+    /// The pipe was run while its last stage doesn't produce the pipe's output type.
+    #[error(
+        "the pipe should output a value of type `{expected}`, but its last stage outputs `{found}`"
+    )]
+    OutputMismatch {
+        /// The output type of the pipe.
+        expected: &'static str,
+        /// The type of output the last stage produces.
+        found: &'static str,
+    },
+    /// A stage was requested by a name that isn't in the [`StageRegistry`].
+    #[error("no stage named `{0}` is registered")]
+    UnknownStage(String),
+}
+
+/// A single type-erased stage of a [`DynPipe`], which remembers its input and output types.
+pub struct DynStage {
+    name: Option<String>,
+    input: TypeInfo,
+    output: TypeInfo,
+    stage: Box<ErasedFn>,
+}
+
+impl DynStage {
+    /// Erases the type of the given stage.
+    #[inline]
+    pub fn new<A: 'static, B: 'static, F: Fn(A) -> B + 'static>(stage: F) -> Self {
+        Self {
+            name: None,
+            input: TypeInfo::of::<A>(),
+            output: TypeInfo::of::<B>(),
+            stage: Box::new(move |input: Box<dyn Any>| {
+                input
+                    .downcast::<A>()
+                    .ok()
+                    .map(|input| -> Box<dyn Any> { Box::new(stage(*input)) })
+            }),
+        }
+    }
+
+    /// Gives this stage a name, which is used to describe it in errors.
+    #[inline]
+    #[must_use]
+    pub fn named<N: Into<String>>(mut self, name: N) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The name of this stage, if it has one.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The name of the type of input this stage expects.
+    #[inline]
+    #[must_use]
+    pub const fn input_type_name(&self) -> &'static str {
+        self.input.name
+    }
+
+    /// The name of the type of output this stage produces.
+    #[inline]
+    #[must_use]
+    pub const fn output_type_name(&self) -> &'static str {
+        self.output.name
+    }
+}
+
+impl fmt::Debug for DynStage {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynStage")
+            .field("name", &self.name)
+            .field("input", &self.input.name)
+            .field("output", &self.output.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A pipe from `In` to `Out` whose stages are pushed one at a time at runtime, with every stage
+/// checked to accept the output of the stage before it.
+pub struct DynPipe<In, Out> {
+    stages: Vec<DynStage>,
+    _marker: PhantomData<fn(In) -> Out>,
+}
+
+impl<In: 'static, Out: 'static> DynPipe<In, Out> {
+    /// Creates an empty pipe, with no stages yet.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            stages: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// The number of stages in this pipe.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    /// Whether this pipe has no stages yet.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+
+    /// The stages of this pipe, in order.
+    #[inline]
+    #[must_use]
+    pub fn stages(&self) -> &[DynStage] {
+        &self.stages
+    }
+
+    /// The type that the last stage outputs, which is the pipe's input type if there are no stages.
+    fn tail(&self) -> TypeInfo {
+        self.stages
+            .last()
+            .map_or_else(TypeInfo::of::<In>, |stage| stage.output)
+    }
+
+    /// Adds a stage to the end of this pipe, so long as it accepts the output of the current last
+    /// stage (or the pipe's input, if there are no stages yet).
+    ///
+    /// # Errors
+    /// Returns [`DynPipeError::StageMismatch`] if the stage's input type doesn't match.
+    #[inline]
+    pub fn push_stage(&mut self, stage: DynStage) -> Result<&mut Self, DynPipeError> {
+        let tail = self.tail();
+        if stage.input.id != tail.id {
+            return Err(DynPipeError::StageMismatch {
+                index: self.stages.len(),
+                name: stage.name,
+                expected: stage.input.name,
+                found: tail.name,
+            });
+        }
+        self.stages.push(stage);
+        Ok(self)
+    }
+
+    /// Adds a function to the end of this pipe, as with [`DynPipe::push_stage`].
+    ///
+    /// # Errors
+    /// Returns [`DynPipeError::StageMismatch`] if the function's input type doesn't match.
+    #[inline]
+    pub fn push<A: 'static, B: 'static, F: Fn(A) -> B + 'static>(
+        &mut self,
+        stage: F,
+    ) -> Result<&mut Self, DynPipeError> {
+        self.push_stage(DynStage::new(stage))
+    }
+
+    /// Checks that the last stage of this pipe produces the pipe's output type, i.e. that the pipe
+    /// is complete and can be run.
+    ///
+    /// # Errors
+    /// Returns [`DynPipeError::OutputMismatch`] if the last stage outputs some other type.
+    #[inline]
+    pub fn validate(&self) -> Result<(), DynPipeError> {
+        let tail = self.tail();
+        if tail.id == TypeId::of::<Out>() {
+            Ok(())
+        } else {
+            Err(DynPipeError::OutputMismatch {
+                expected: type_name::<Out>(),
+                found: tail.name,
+            })
+        }
+    }
+
+    /// Runs the input through every stage of this pipe, in order.
+    ///
+    /// # Errors
+    /// Returns [`DynPipeError::OutputMismatch`] if the pipe isn't complete, see
+    /// [`DynPipe::validate`].
+    #[inline]
+    pub fn run(&self, input: In) -> Result<Out, DynPipeError> {
+        self.validate()?;
+
+        // the stages were checked against one another as they were pushed, and the pipe as a
+        // whole was just validated, so the types can never actually mismatch here
+        let mut value: Box<dyn Any> = Box::new(input);
+        let mut found = TypeInfo::of::<In>();
+        for (index, stage) in self.stages.iter().enumerate() {
+            value = (stage.stage)(value).ok_or_else(|| DynPipeError::StageMismatch {
+                index,
+                name: stage.name.clone(),
+                expected: stage.input.name,
+                found: found.name,
+            })?;
+            found = stage.output;
+        }
+        value
+            .downcast::<Out>()
+            .map(|output| *output)
+            .map_err(|_| DynPipeError::OutputMismatch {
+                expected: type_name::<Out>(),
+                found: self.tail().name,
+            })
+    }
+}
+
+impl<In: 'static, Out: 'static> Default for DynPipe<In, Out> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<In, Out> fmt::Debug for DynPipe<In, Out> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynPipe")
+            .field("stages", &self.stages)
+            .finish()
+    }
+}
+
+/// A registry of named stage constructors, so that a [`DynPipe`] can be assembled from a list of
+/// stage names, e.g. as read from configuration.
+#[derive(Default)]
+pub struct StageRegistry {
+    constructors: HashMap<String, Box<dyn Fn() -> DynStage>>,
+}
+
+impl StageRegistry {
+    /// Creates an empty registry.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a constructor of stages under the given name, replacing any previous one.
+    #[inline]
+    pub fn register<A, B, F, N, C>(&mut self, name: N, constructor: C) -> &mut Self
+    where
+        A: 'static,
+        B: 'static,
+        F: Fn(A) -> B + 'static,
+        N: Into<String>,
+        C: Fn() -> F + 'static,
+    {
+        let name = name.into();
+        let stage_name = name.clone();
+        self.constructors.insert(
+            name,
+            Box::new(move || DynStage::new(constructor()).named(stage_name.clone())),
+        );
+        self
+    }
+
+    /// Registers a stage under the given name, which is cloned whenever it is used.
+    #[inline]
+    pub fn register_fn<A, B, F, N>(&mut self, name: N, stage: F) -> &mut Self
+    where
+        A: 'static,
+        B: 'static,
+        F: Fn(A) -> B + Clone + 'static,
+        N: Into<String>,
+    {
+        self.register(name, move || stage.clone())
+    }
+
+    /// Whether a stage is registered under the given name.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.constructors.contains_key(name)
+    }
+
+    /// Constructs the stage registered under the given name.
+    ///
+    /// # Errors
+    /// Returns [`DynPipeError::UnknownStage`] if no stage is registered under that name.
+    #[inline]
+    pub fn stage(&self, name: &str) -> Result<DynStage, DynPipeError> {
+        self.constructors
+            .get(name)
+            .map(|constructor| constructor())
+            .ok_or_else(|| DynPipeError::UnknownStage(name.to_owned()))
+    }
+
+    /// Assembles a pipe out of the stages registered under the given names, in order, and checks
+    /// that it is complete.
+    ///
+    /// # Errors
+    /// Returns a [`DynPipeError`] if any of the names aren't registered, if any adjacent stages
+    /// don't match up, or if the last stage doesn't output `Out`.
+    #[inline]
+    pub fn build<In: 'static, Out: 'static, I>(
+        &self,
+        names: I,
+    ) -> Result<DynPipe<In, Out>, DynPipeError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut pipe = DynPipe::new();
+        for name in names {
+            pipe.push_stage(self.stage(name.as_ref())?)?;
+        }
+        pipe.validate()?;
+        Ok(pipe)
+    }
+}
+
+impl fmt::Debug for StageRegistry {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.constructors.keys()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dyn_pipe::{DynPipe, DynPipeError, StageRegistry};
+    use std::any::type_name;
+
+    fn registry() -> StageRegistry {
+        let mut registry = StageRegistry::new();
+        registry
+            .register_fn("parse", |s: String| {
+                s.trim().parse::<i64>().unwrap_or_default()
+            })
+            .register_fn("double", |n: i64| n.saturating_mul(2))
+            .register_fn("show", |n: i64| n.to_string());
+        registry
+    }
+
+    #[test]
+    fn builds_from_names() -> Result<(), DynPipeError> {
+        let pipe = registry().build::<String, String, _>(["parse", "double", "double", "show"])?;
+        assert_eq!(pipe.len(), 4);
+        assert_eq!(pipe.run(" 21 ".to_owned()), Ok("84".to_owned()));
+        Ok(())
+    }
+
+    #[test]
+    fn reports_mismatches() {
+        let registry = registry();
+        assert_eq!(
+            registry
+                .build::<String, String, _>(["parse", "parse"])
+                .err(),
+            Some(DynPipeError::StageMismatch {
+                index: 1,
+                name: Some("parse".to_owned()),
+                expected: type_name::<String>(),
+                found: type_name::<i64>(),
+            })
+        );
+        assert_eq!(
+            registry.build::<String, String, _>(["parse"]).err(),
+            Some(DynPipeError::OutputMismatch {
+                expected: type_name::<String>(),
+                found: type_name::<i64>(),
+            })
+        );
+        assert_eq!(
+            registry.build::<String, String, _>(["halve"]).err(),
+            Some(DynPipeError::UnknownStage("halve".to_owned()))
+        );
+
+        let mut pipe = DynPipe::<i64, i64>::new();
+        assert_eq!(
+            pipe.push(|s: String| s.len())
+                .err()
+                .map(|error| error.to_string()),
+            Some(format!(
+                "stage 0 expects an input of type `{}`, but the pipe's input is of type `{}`",
+                type_name::<String>(),
+                type_name::<i64>()
+            ))
+        );
+    }
+}
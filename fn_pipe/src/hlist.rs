@@ -0,0 +1,174 @@
+//! Pipes backed by [`HList`](typelevel::data::hlist::HList)s of stages rather than tuples, which are
+//! implemented recursively and so can have any number of stages.
+//!
+//! An `HCons` pipe runs its head stage and feeds the output into its tail pipe, while the `HNil`
+//! pipe simply passes its single argument through unchanged.
+
+#[cfg(feature = "stable")]
+use crate::stable::{self, TupleFn, TupleFnMut, TupleFnOnce};
+#[cfg(feature = "nightly")]
+use crate::{FnMutPipe, FnOncePipe, FnPipe};
+use fn_pipe_proc::impl_hlist_conversion_for_tuple;
+#[cfg(feature = "nightly")]
+use std::marker::Tuple;
+use typelevel::data::hlist::{HCons, HNil};
+
+/// A trait for tuples that can be converted into their equivalent `HList`.
+pub trait IntoHList {
+    type Output;
+
+    fn into_hlist(self) -> Self::Output;
+}
+
+/// A trait for `HList`s that can be converted into their equivalent tuple.
+pub trait IntoTuple {
+    type Output;
+
+    fn into_tuple(self) -> Self::Output;
+}
+
+#[cfg(feature = "nightly")]
+impl<A> FnPipe<(A,)> for HNil {
+    #[inline]
+    extern "rust-call" fn run(&self, (a,): (A,)) -> Self::Output {
+        a
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A> FnMutPipe<(A,)> for HNil {
+    #[inline]
+    extern "rust-call" fn run_mut(&mut self, (a,): (A,)) -> Self::Output {
+        a
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A> FnOncePipe<(A,)> for HNil {
+    type Output = A;
+
+    #[inline]
+    extern "rust-call" fn run_once(self, (a,): (A,)) -> Self::Output {
+        a
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<Args: Tuple, H: Fn<Args>, T: FnPipe<(H::Output,)>> FnPipe<Args> for HCons<H, T> {
+    #[inline]
+    extern "rust-call" fn run(&self, args: Args) -> Self::Output {
+        self.tail.run((self.head.call(args),))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<Args: Tuple, H: FnMut<Args>, T: FnMutPipe<(H::Output,)>> FnMutPipe<Args> for HCons<H, T> {
+    #[inline]
+    extern "rust-call" fn run_mut(&mut self, args: Args) -> Self::Output {
+        self.tail.run_mut((self.head.call_mut(args),))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<Args: Tuple, H: FnOnce<Args>, T: FnOncePipe<(H::Output,)>> FnOncePipe<Args> for HCons<H, T> {
+    type Output = T::Output;
+
+    #[inline]
+    extern "rust-call" fn run_once(self, args: Args) -> Self::Output {
+        self.tail.run_once((self.head.call_once(args),))
+    }
+}
+
+#[cfg(feature = "stable")]
+impl<A> stable::FnPipe<(A,)> for HNil {
+    #[inline]
+    fn run(&self, (a,): (A,)) -> Self::Output {
+        a
+    }
+}
+
+#[cfg(feature = "stable")]
+impl<A> stable::FnMutPipe<(A,)> for HNil {
+    #[inline]
+    fn run_mut(&mut self, (a,): (A,)) -> Self::Output {
+        a
+    }
+}
+
+#[cfg(feature = "stable")]
+impl<A> stable::FnOncePipe<(A,)> for HNil {
+    type Output = A;
+
+    #[inline]
+    fn run_once(self, (a,): (A,)) -> Self::Output {
+        a
+    }
+}
+
+#[cfg(feature = "stable")]
+impl<Args, H: TupleFn<Args>, T: stable::FnPipe<(H::Output,)>> stable::FnPipe<Args> for HCons<H, T> {
+    #[inline]
+    fn run(&self, args: Args) -> Self::Output {
+        self.tail.run((self.head.call_tuple(args),))
+    }
+}
+
+#[cfg(feature = "stable")]
+impl<Args, H: TupleFnMut<Args>, T: stable::FnMutPipe<(H::Output,)>> stable::FnMutPipe<Args>
+    for HCons<H, T>
+{
+    #[inline]
+    fn run_mut(&mut self, args: Args) -> Self::Output {
+        self.tail.run_mut((self.head.call_tuple_mut(args),))
+    }
+}
+
+#[cfg(feature = "stable")]
+impl<Args, H: TupleFnOnce<Args>, T: stable::FnOncePipe<(H::Output,)>> stable::FnOncePipe<Args>
+    for HCons<H, T>
+{
+    type Output = T::Output;
+
+    #[inline]
+    fn run_once(self, args: Args) -> Self::Output {
+        self.tail.run_once((self.head.call_tuple_once(args),))
+    }
+}
+
+// implement the conversions for tuples of upto length 26, to match the `Fn/Pipe*` variants
+impl_hlist_conversion_for_tuple!(26usize);
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "nightly")]
+    use crate::FnPipe as _;
+    use crate::hlist::{IntoHList as _, IntoTuple as _};
+    #[cfg(all(feature = "stable", not(feature = "nightly")))]
+    use crate::stable::FnPipe as _;
+    use typelevel::data::hlist::HNil;
+
+    #[test]
+    fn runs_past_the_tuple_limit() {
+        let inc = |n: u32| n.saturating_add(1);
+        let pipe = (
+            inc, inc, inc, inc, inc, inc, inc, inc, inc, inc, inc, inc, inc,
+        )
+            .into_hlist()
+            + (
+                inc, inc, inc, inc, inc, inc, inc, inc, inc, inc, inc, inc, inc,
+            )
+                .into_hlist();
+        let pipe = pipe.prepend(|s: &str| s.len().try_into().unwrap_or(u32::MAX));
+
+        assert_eq!(pipe.len(), 27);
+        assert_eq!(pipe.run(("four",)), 30);
+    }
+
+    #[test]
+    fn converts_to_and_from_tuples() {
+        let pipe = (|a: u8, b: u8| a.saturating_add(b), |n: u8| n.to_string()).into_hlist();
+        assert_eq!(pipe.run((1, 2)), "3");
+        assert_eq!(pipe.into_tuple().run((3, 4)), "7");
+        assert_eq!(HNil.run(("unchanged",)), "unchanged");
+    }
+}
@@ -0,0 +1,899 @@
+//! Runtime instrumentation of pipes, where an observer is notified as each stage starts and ends,
+//! along with a [`Debug`] view of the values flowing through the stages that implement it.
+//!
+//! Whether a value implements [`Debug`] can't be told within the generic pipe implementations, so
+//! it is told where the stages are written instead: the [`instrumented!`](crate::instrumented)
+//! macro wraps every stage in whichever of [`Debugged`], [`DebuggedInput`] and [`DebuggedOutput`]
+//! fits it. Pipes instrumented otherwise only report views for stages wrapped in those by hand.
+
+use fn_pipe_proc::impl_observed_fn_pipe_for_tuple;
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::marker::{PhantomData, Tuple};
+use std::time::{Duration, Instant};
+
+/// Information about a stage of a pipe that is being run.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StageInfo {
+    /// The position of this stage within the pipe, starting from zero.
+    pub index: usize,
+    /// The total number of stages in the pipe.
+    pub count: usize,
+    /// The name of this stage, if it was given one with [`Named`].
+    pub name: Option<&'static str>,
+}
+
+/// A trait for observers of pipes, which get notified as each stage of the pipe starts and ends.
+///
+/// The notifications take `&self` so that observers can be shared between pipes (and nested
+/// pipes), meaning stateful observers will need interior mutability.
+pub trait PipeObserver {
+    /// Called right before a stage runs, with a [`Debug`] view of its arguments if the stage
+    /// reports them, see [`DebuggedInput`].
+    fn on_stage_start(&self, stage: StageInfo, input: Option<&dyn Debug>);
+
+    /// Called right after a stage ran, with a [`Debug`] view of its output if the stage reports it,
+    /// see [`DebuggedOutput`].
+    fn on_stage_end(&self, stage: StageInfo, output: Option<&dyn Debug>);
+}
+
+/// A trait for pipes that know how many stages they consist of.
+pub trait StageCount {
+    const STAGE_COUNT: usize;
+}
+
+/// A trait for the stages of instrumented pipes, i.e. any function, which can additionally be
+/// wrapped in [`Named`] and/or [`Debugged`] to give observers more to report.
+///
+/// [`DebuggedInput`] and [`DebuggedOutput`] stand in for [`Debugged`] when only one of the
+/// arguments and output of a stage implements [`Debug`].
+///
+/// The `Kind` parameter is one of the [`kind`] markers, and keeps the implementation for plain
+/// functions apart from those for the wrappers; it is always inferred.
+pub trait Stage<Args: Tuple, Kind>: StageMut<Args, Kind> {
+    fn call_stage(&self, args: Args) -> Self::Output;
+}
+
+pub trait StageMut<Args: Tuple, Kind>: StageOnce<Args, Kind> {
+    fn call_stage_mut(&mut self, args: Args) -> Self::Output;
+}
+
+pub trait StageOnce<Args: Tuple, Kind> {
+    type Output;
+
+    /// The name of this stage, if it was given one with [`Named`].
+    #[inline]
+    fn stage_name(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// A [`Debug`] view of the arguments of this stage, if it is [`Debugged`] or [`DebuggedInput`].
+    #[inline]
+    fn debug_input(_args: &Args) -> Option<&dyn Debug> {
+        None
+    }
+
+    /// A [`Debug`] view of the output of this stage, if it is [`Debugged`] or [`DebuggedOutput`].
+    #[inline]
+    fn debug_output(_output: &Self::Output) -> Option<&dyn Debug> {
+        None
+    }
+
+    fn call_stage_once(self, args: Args) -> Self::Output;
+}
+
+/// Markers for the kinds of [`StageOnce`] implementations, see [`Stage`].
+pub mod kind {
+    use std::marker::PhantomData;
+
+    /// The kind of plain functions.
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+    pub struct Function;
+
+    /// The kind of [`Named`](super::Named) stages wrapping a stage of kind `K`.
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+    pub struct Named<K>(PhantomData<K>);
+
+    /// The kind of [`Debugged`](super::Debugged) stages wrapping a stage of kind `K`.
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+    pub struct Debugged<K>(PhantomData<K>);
+
+    /// The kind of [`DebuggedInput`](super::DebuggedInput) stages wrapping a stage of kind `K`.
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+    pub struct DebuggedInput<K>(PhantomData<K>);
+
+    /// The kind of [`DebuggedOutput`](super::DebuggedOutput) stages wrapping a stage of kind `K`.
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+    pub struct DebuggedOutput<K>(PhantomData<K>);
+}
+
+/// A trait representing a pipe of functions, where every stage is run through an observer
+///
+/// The `Kinds` parameter is the tuple of the [`kind`]s of every stage.
+pub trait ObservedFnPipe<Args: Tuple, Kinds>: ObservedFnMutPipe<Args, Kinds> {
+    fn run_observed<Obs: PipeObserver + ?Sized>(&self, observer: &Obs, args: Args) -> Self::Output;
+}
+
+pub trait ObservedFnMutPipe<Args: Tuple, Kinds>: ObservedFnOncePipe<Args, Kinds> {
+    fn run_mut_observed<Obs: PipeObserver + ?Sized>(
+        &mut self,
+        observer: &Obs,
+        args: Args,
+    ) -> Self::Output;
+}
+
+pub trait ObservedFnOncePipe<Args: Tuple, Kinds>: StageCount {
+    type Output;
+
+    fn run_once_observed<Obs: PipeObserver + ?Sized>(
+        self,
+        observer: &Obs,
+        args: Args,
+    ) -> Self::Output;
+}
+
+/// A stage-wrapper which gives the wrapped stage a name, to be reported to observers.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Named<F> {
+    pub name: &'static str,
+    pub stage: F,
+}
+
+/// Wraps the stage as a [`Named`] stage.
+#[inline]
+pub const fn named<F>(name: &'static str, stage: F) -> Named<F> {
+    Named { name, stage }
+}
+
+/// A stage-wrapper which opts the wrapped stage into reporting [`Debug`] views of its arguments
+/// and output to observers, and so requires both of them to implement it.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[repr(transparent)]
+pub struct Debugged<F>(pub F);
+
+/// Wraps the stage as a [`Debugged`] stage.
+#[inline]
+pub const fn debugged<F>(stage: F) -> Debugged<F> {
+    Debugged(stage)
+}
+
+/// A stage-wrapper like [`Debugged`], for stages of which only the arguments implement [`Debug`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[repr(transparent)]
+pub struct DebuggedInput<F>(pub F);
+
+/// A stage-wrapper like [`Debugged`], for stages of which only the output implements [`Debug`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[repr(transparent)]
+pub struct DebuggedOutput<F>(pub F);
+
+/// Builds an [`Instrumented`] pipe out of the observer and the given stages, in order.
+///
+/// Every stage is wrapped to report [`Debug`] views of whichever of its arguments and output
+/// implement it, e.g. `instrumented!(observer; parse, named("double", double))`.
+///
+/// Which wrapper fits is told from the concrete type of every stage where it is written, so a
+/// closure must have its argument types known by then: any left to be inferred from the stages
+/// before it are taken to implement [`Debug`], failing to compile if they don't.
+#[macro_export]
+macro_rules! instrumented {
+    ($observer:expr; $($stage:expr),+ $(,)?) => {
+        $crate::instrument::Instrumented::new(
+            ($($crate::__auto_debugged!($stage),)+),
+            $observer,
+        )
+    };
+}
+
+/// Wraps the stage in whichever of the debugging wrappers fits it, see
+/// [`instrumented!`](crate::instrumented).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __auto_debugged {
+    ($stage:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::instrument::auto_debug::{
+            ViaDebugged as _, ViaDebuggedInput as _, ViaDebuggedOutput as _, ViaPlain as _,
+        };
+        let stage = $stage;
+        (&&&$crate::instrument::auto_debug::Probe(&stage))
+            .wrapper()
+            .wrap(stage)
+    }};
+}
+
+/// The autoref-based dispatch behind [`instrumented!`](crate::instrumented), which picks the
+/// wrapper of a stage by method resolution: it tries the receiver `&&&Probe` against the
+/// implementations for `&&&Probe`, `&&Probe`, `&Probe` and `Probe` in turn, so the first one whose
+/// bounds the stage meets wins.
+#[doc(hidden)]
+pub mod auto_debug {
+    use crate::instrument::{Debugged, DebuggedInput, DebuggedOutput, StageOnce};
+    use std::fmt::Debug;
+    use std::marker::Tuple;
+
+    /// A reference to a stage, probing which wrapper fits it.
+    pub struct Probe<'a, F>(pub &'a F);
+
+    impl<F> Clone for Probe<'_, F> {
+        #[inline]
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<F> Copy for Probe<'_, F> {}
+
+    /// Wraps stages in [`Debugged`].
+    pub struct WrapDebugged;
+
+    /// Wraps stages in [`DebuggedInput`].
+    pub struct WrapDebuggedInput;
+
+    /// Wraps stages in [`DebuggedOutput`].
+    pub struct WrapDebuggedOutput;
+
+    /// Leaves stages as they are.
+    pub struct WrapPlain;
+
+    pub trait ViaDebugged<Args, K> {
+        fn wrapper(self) -> WrapDebugged;
+    }
+
+    pub trait ViaDebuggedInput<Args, K> {
+        fn wrapper(self) -> WrapDebuggedInput;
+    }
+
+    pub trait ViaDebuggedOutput<Args, K> {
+        fn wrapper(self) -> WrapDebuggedOutput;
+    }
+
+    pub trait ViaPlain {
+        fn wrapper(self) -> WrapPlain;
+    }
+
+    impl<Args: Tuple + Debug, K, F: StageOnce<Args, K, Output: Debug>> ViaDebugged<Args, K>
+        for &&&Probe<'_, F>
+    {
+        #[inline]
+        fn wrapper(self) -> WrapDebugged {
+            WrapDebugged
+        }
+    }
+
+    impl<Args: Tuple + Debug, K, F: StageOnce<Args, K>> ViaDebuggedInput<Args, K> for &&Probe<'_, F> {
+        #[inline]
+        fn wrapper(self) -> WrapDebuggedInput {
+            WrapDebuggedInput
+        }
+    }
+
+    impl<Args: Tuple, K, F: StageOnce<Args, K, Output: Debug>> ViaDebuggedOutput<Args, K>
+        for &Probe<'_, F>
+    {
+        #[inline]
+        fn wrapper(self) -> WrapDebuggedOutput {
+            WrapDebuggedOutput
+        }
+    }
+
+    impl<F> ViaPlain for Probe<'_, F> {
+        #[inline]
+        fn wrapper(self) -> WrapPlain {
+            WrapPlain
+        }
+    }
+
+    impl WrapDebugged {
+        #[inline]
+        pub const fn wrap<F>(self, stage: F) -> Debugged<F> {
+            Debugged(stage)
+        }
+    }
+
+    impl WrapDebuggedInput {
+        #[inline]
+        pub const fn wrap<F>(self, stage: F) -> DebuggedInput<F> {
+            DebuggedInput(stage)
+        }
+    }
+
+    impl WrapDebuggedOutput {
+        #[inline]
+        pub const fn wrap<F>(self, stage: F) -> DebuggedOutput<F> {
+            DebuggedOutput(stage)
+        }
+    }
+
+    impl WrapPlain {
+        #[inline]
+        pub const fn wrap<F>(self, stage: F) -> F {
+            stage
+        }
+    }
+}
+
+/// A pipe which reports the progress of every one of its stages to its observer, while otherwise
+/// running exactly as the wrapped pipe would.
+///
+/// The `Kinds` parameter is that of [`ObservedFnPipe`], and is inferred once the pipe is run.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Instrumented<P, O, Kinds> {
+    pipe: P,
+    observer: O,
+    kinds: PhantomData<Kinds>,
+}
+
+/// Information about how long a stage of a pipe took to run.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StageTiming {
+    pub stage: StageInfo,
+    pub elapsed: Duration,
+}
+
+/// An observer which records how long every stage took to run.
+#[derive(Debug, Default)]
+pub struct TimingObserver {
+    started: RefCell<Vec<Instant>>,
+    timings: RefCell<Vec<StageTiming>>,
+}
+
+/// A single notification received by [`CollectingObserver`], with the values formatted as strings.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum StageEvent {
+    Start {
+        stage: StageInfo,
+        input: Option<String>,
+    },
+    End {
+        stage: StageInfo,
+        output: Option<String>,
+    },
+}
+
+/// An observer which collects every notification it receives, mostly useful for tests.
+#[derive(Debug, Default)]
+pub struct CollectingObserver {
+    events: RefCell<Vec<StageEvent>>,
+}
+
+/// Runs a single stage `S`, notifying the observer right before and right after.
+#[inline]
+fn observe_stage<Obs: PipeObserver + ?Sized, Args: Tuple, S: StageOnce<Args, K>, K, F>(
+    observer: &Obs,
+    stage: StageInfo,
+    input: Args,
+    f: F,
+) -> S::Output
+where
+    F: FnOnce(Args) -> S::Output,
+{
+    observer.on_stage_start(stage, S::debug_input(&input));
+    let output = f(input);
+    observer.on_stage_end(stage, S::debug_output(&output));
+    output
+}
+
+// implement `ObservedFn/Pipe*` variants for tuples of upto length 26, to match the `Fn/Pipe*` variants
+impl_observed_fn_pipe_for_tuple!(26usize);
+
+mod impls {
+    use crate::instrument::{
+        CollectingObserver, Debugged, DebuggedInput, DebuggedOutput, Instrumented, Named,
+        ObservedFnMutPipe, ObservedFnOncePipe, ObservedFnPipe, PipeObserver, Stage, StageCount,
+        StageEvent, StageInfo, StageMut, StageOnce, StageTiming, TimingObserver, kind,
+    };
+    use crate::{FnMutPipe, FnOncePipe, FnPipe};
+    use std::fmt::Debug;
+    use std::marker::{PhantomData, Tuple};
+    use std::time::Instant;
+
+    impl StageInfo {
+        #[inline]
+        #[must_use]
+        pub const fn new(index: usize, count: usize, name: Option<&'static str>) -> Self {
+            Self { index, count, name }
+        }
+    }
+
+    impl<O: PipeObserver + ?Sized> PipeObserver for &O {
+        #[inline]
+        fn on_stage_start(&self, stage: StageInfo, input: Option<&dyn Debug>) {
+            O::on_stage_start(self, stage, input);
+        }
+
+        #[inline]
+        fn on_stage_end(&self, stage: StageInfo, output: Option<&dyn Debug>) {
+            O::on_stage_end(self, stage, output);
+        }
+    }
+
+    // every function is a stage, which is neither named nor debugged
+    impl<Args: Tuple, F: FnOnce<Args>> StageOnce<Args, kind::Function> for F {
+        type Output = F::Output;
+
+        #[inline]
+        fn call_stage_once(self, args: Args) -> Self::Output {
+            self.call_once(args)
+        }
+    }
+
+    impl<Args: Tuple, F: FnMut<Args>> StageMut<Args, kind::Function> for F {
+        #[inline]
+        fn call_stage_mut(&mut self, args: Args) -> Self::Output {
+            self.call_mut(args)
+        }
+    }
+
+    impl<Args: Tuple, F: Fn<Args>> Stage<Args, kind::Function> for F {
+        #[inline]
+        fn call_stage(&self, args: Args) -> Self::Output {
+            self.call(args)
+        }
+    }
+
+    // `Named` reports its name, and otherwise delegates to the wrapped stage
+    impl<Args: Tuple, K, F: StageOnce<Args, K>> StageOnce<Args, kind::Named<K>> for Named<F> {
+        type Output = F::Output;
+
+        #[inline]
+        fn stage_name(&self) -> Option<&'static str> {
+            Some(self.name)
+        }
+
+        #[inline]
+        fn debug_input(args: &Args) -> Option<&dyn Debug> {
+            F::debug_input(args)
+        }
+
+        #[inline]
+        fn debug_output(output: &Self::Output) -> Option<&dyn Debug> {
+            F::debug_output(output)
+        }
+
+        #[inline]
+        fn call_stage_once(self, args: Args) -> Self::Output {
+            self.stage.call_stage_once(args)
+        }
+    }
+
+    impl<Args: Tuple, K, F: StageMut<Args, K>> StageMut<Args, kind::Named<K>> for Named<F> {
+        #[inline]
+        fn call_stage_mut(&mut self, args: Args) -> Self::Output {
+            self.stage.call_stage_mut(args)
+        }
+    }
+
+    impl<Args: Tuple, K, F: Stage<Args, K>> Stage<Args, kind::Named<K>> for Named<F> {
+        #[inline]
+        fn call_stage(&self, args: Args) -> Self::Output {
+            self.stage.call_stage(args)
+        }
+    }
+
+    // `Debugged` reports its arguments and output, and otherwise delegates to the wrapped stage
+    impl<Args: Tuple + Debug, K, F: StageOnce<Args, K, Output: Debug>>
+        StageOnce<Args, kind::Debugged<K>> for Debugged<F>
+    {
+        type Output = F::Output;
+
+        #[inline]
+        fn stage_name(&self) -> Option<&'static str> {
+            self.0.stage_name()
+        }
+
+        #[inline]
+        fn debug_input(args: &Args) -> Option<&dyn Debug> {
+            Some(args)
+        }
+
+        #[inline]
+        fn debug_output(output: &Self::Output) -> Option<&dyn Debug> {
+            Some(output)
+        }
+
+        #[inline]
+        fn call_stage_once(self, args: Args) -> Self::Output {
+            self.0.call_stage_once(args)
+        }
+    }
+
+    impl<Args: Tuple + Debug, K, F: StageMut<Args, K, Output: Debug>>
+        StageMut<Args, kind::Debugged<K>> for Debugged<F>
+    {
+        #[inline]
+        fn call_stage_mut(&mut self, args: Args) -> Self::Output {
+            self.0.call_stage_mut(args)
+        }
+    }
+
+    impl<Args: Tuple + Debug, K, F: Stage<Args, K, Output: Debug>> Stage<Args, kind::Debugged<K>>
+        for Debugged<F>
+    {
+        #[inline]
+        fn call_stage(&self, args: Args) -> Self::Output {
+            self.0.call_stage(args)
+        }
+    }
+
+    // `DebuggedInput` reports its arguments, and otherwise delegates to the wrapped stage
+    impl<Args: Tuple + Debug, K, F: StageOnce<Args, K>> StageOnce<Args, kind::DebuggedInput<K>>
+        for DebuggedInput<F>
+    {
+        type Output = F::Output;
+
+        #[inline]
+        fn stage_name(&self) -> Option<&'static str> {
+            self.0.stage_name()
+        }
+
+        #[inline]
+        fn debug_input(args: &Args) -> Option<&dyn Debug> {
+            Some(args)
+        }
+
+        #[inline]
+        fn debug_output(output: &Self::Output) -> Option<&dyn Debug> {
+            F::debug_output(output)
+        }
+
+        #[inline]
+        fn call_stage_once(self, args: Args) -> Self::Output {
+            self.0.call_stage_once(args)
+        }
+    }
+
+    impl<Args: Tuple + Debug, K, F: StageMut<Args, K>> StageMut<Args, kind::DebuggedInput<K>>
+        for DebuggedInput<F>
+    {
+        #[inline]
+        fn call_stage_mut(&mut self, args: Args) -> Self::Output {
+            self.0.call_stage_mut(args)
+        }
+    }
+
+    impl<Args: Tuple + Debug, K, F: Stage<Args, K>> Stage<Args, kind::DebuggedInput<K>>
+        for DebuggedInput<F>
+    {
+        #[inline]
+        fn call_stage(&self, args: Args) -> Self::Output {
+            self.0.call_stage(args)
+        }
+    }
+
+    // `DebuggedOutput` reports its output, and otherwise delegates to the wrapped stage
+    impl<Args: Tuple, K, F: StageOnce<Args, K, Output: Debug>>
+        StageOnce<Args, kind::DebuggedOutput<K>> for DebuggedOutput<F>
+    {
+        type Output = F::Output;
+
+        #[inline]
+        fn stage_name(&self) -> Option<&'static str> {
+            self.0.stage_name()
+        }
+
+        #[inline]
+        fn debug_input(args: &Args) -> Option<&dyn Debug> {
+            F::debug_input(args)
+        }
+
+        #[inline]
+        fn debug_output(output: &Self::Output) -> Option<&dyn Debug> {
+            Some(output)
+        }
+
+        #[inline]
+        fn call_stage_once(self, args: Args) -> Self::Output {
+            self.0.call_stage_once(args)
+        }
+    }
+
+    impl<Args: Tuple, K, F: StageMut<Args, K, Output: Debug>>
+        StageMut<Args, kind::DebuggedOutput<K>> for DebuggedOutput<F>
+    {
+        #[inline]
+        fn call_stage_mut(&mut self, args: Args) -> Self::Output {
+            self.0.call_stage_mut(args)
+        }
+    }
+
+    impl<Args: Tuple, K, F: Stage<Args, K, Output: Debug>> Stage<Args, kind::DebuggedOutput<K>>
+        for DebuggedOutput<F>
+    {
+        #[inline]
+        fn call_stage(&self, args: Args) -> Self::Output {
+            self.0.call_stage(args)
+        }
+    }
+
+    impl<P, O, Kinds> Instrumented<P, O, Kinds> {
+        /// Wraps the pipe, so that it reports to the observer whenever it is run.
+        #[inline]
+        pub const fn new(pipe: P, observer: O) -> Self {
+            Self {
+                pipe,
+                observer,
+                kinds: PhantomData,
+            }
+        }
+
+        /// Borrows the observer, e.g. to inspect what it has recorded so far.
+        #[inline]
+        pub const fn observer(&self) -> &O {
+            &self.observer
+        }
+
+        /// Unwraps the underlying pipe and observer.
+        #[inline]
+        pub fn into_parts(self) -> (P, O) {
+            (self.pipe, self.observer)
+        }
+
+        /// The number of stages of the underlying pipe.
+        #[inline]
+        #[must_use]
+        pub const fn stage_count(&self) -> usize
+        where
+            P: StageCount,
+        {
+            P::STAGE_COUNT
+        }
+    }
+
+    impl<P: StageCount, O, Kinds> StageCount for Instrumented<P, O, Kinds> {
+        const STAGE_COUNT: usize = P::STAGE_COUNT;
+    }
+
+    impl<Args: Tuple, Kinds, P: ObservedFnPipe<Args, Kinds>, O: PipeObserver> FnPipe<Args>
+        for Instrumented<P, O, Kinds>
+    {
+        #[inline]
+        extern "rust-call" fn run(&self, args: Args) -> Self::Output {
+            self.pipe.run_observed(&self.observer, args)
+        }
+    }
+
+    impl<Args: Tuple, Kinds, P: ObservedFnMutPipe<Args, Kinds>, O: PipeObserver> FnMutPipe<Args>
+        for Instrumented<P, O, Kinds>
+    {
+        #[inline]
+        extern "rust-call" fn run_mut(&mut self, args: Args) -> Self::Output {
+            self.pipe.run_mut_observed(&self.observer, args)
+        }
+    }
+
+    impl<Args: Tuple, Kinds, P: ObservedFnOncePipe<Args, Kinds>, O: PipeObserver> FnOncePipe<Args>
+        for Instrumented<P, O, Kinds>
+    {
+        type Output = P::Output;
+
+        #[inline]
+        extern "rust-call" fn run_once(self, args: Args) -> Self::Output {
+            self.pipe.run_once_observed(&self.observer, args)
+        }
+    }
+
+    impl TimingObserver {
+        #[inline]
+        #[must_use]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// The timings recorded so far, in the order that the stages ended.
+        #[inline]
+        #[must_use]
+        pub fn timings(&self) -> Vec<StageTiming> {
+            self.timings.borrow().clone()
+        }
+
+        /// Takes the timings recorded so far, leaving none behind.
+        #[inline]
+        #[must_use]
+        pub fn take_timings(&self) -> Vec<StageTiming> {
+            self.timings.take()
+        }
+    }
+
+    impl PipeObserver for TimingObserver {
+        #[inline]
+        fn on_stage_start(&self, _stage: StageInfo, _input: Option<&dyn Debug>) {
+            self.started.borrow_mut().push(Instant::now());
+        }
+
+        #[inline]
+        fn on_stage_end(&self, stage: StageInfo, _output: Option<&dyn Debug>) {
+            // stages may be nested pipes reporting to this same observer, so starts form a stack
+            let elapsed = self
+                .started
+                .borrow_mut()
+                .pop()
+                .map(|started| started.elapsed())
+                .unwrap_or_default();
+            self.timings
+                .borrow_mut()
+                .push(StageTiming { stage, elapsed });
+        }
+    }
+
+    impl CollectingObserver {
+        #[inline]
+        #[must_use]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// The events collected so far, in the order that they happened.
+        #[inline]
+        #[must_use]
+        pub fn events(&self) -> Vec<StageEvent> {
+            self.events.borrow().clone()
+        }
+
+        /// Takes the events collected so far, leaving none behind.
+        #[inline]
+        #[must_use]
+        pub fn take_events(&self) -> Vec<StageEvent> {
+            self.events.take()
+        }
+    }
+
+    impl PipeObserver for CollectingObserver {
+        #[inline]
+        fn on_stage_start(&self, stage: StageInfo, input: Option<&dyn Debug>) {
+            let input = input.map(|input| format!("{input:?}"));
+            self.events
+                .borrow_mut()
+                .push(StageEvent::Start { stage, input });
+        }
+
+        #[inline]
+        fn on_stage_end(&self, stage: StageInfo, output: Option<&dyn Debug>) {
+            let output = output.map(|output| format!("{output:?}"));
+            self.events
+                .borrow_mut()
+                .push(StageEvent::End { stage, output });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::instrument::{
+        CollectingObserver, Instrumented, StageEvent, StageInfo, TimingObserver, debugged, named,
+    };
+    use crate::{FnMutPipe as _, FnOncePipe as _, FnPipe as _, pipe, piped};
+
+    /// A value without a [`Debug`](std::fmt::Debug) implementation.
+    struct Opaque(usize);
+
+    #[test]
+    fn collects_every_stage() {
+        let pipe = Instrumented::new(
+            (
+                named("parse", debugged(|s: &str| s.len())),
+                |n: usize| Opaque(n.pow(2)),
+                |o: Opaque| o.0,
+                debugged(|n: usize| n.saturating_add(1)),
+            ),
+            CollectingObserver::new(),
+        );
+        assert_eq!(pipe.stage_count(), 4);
+        assert_eq!(pipe.run(("abc",)), 10);
+
+        let parse = StageInfo::new(0, 4, Some("parse"));
+        let square = StageInfo::new(1, 4, None);
+        let unwrap = StageInfo::new(2, 4, None);
+        let increment = StageInfo::new(3, 4, None);
+        let some = |s: &str| Some(s.to_owned());
+        assert_eq!(
+            pipe.observer().take_events(),
+            vec![
+                StageEvent::Start {
+                    stage: parse,
+                    input: some("(\"abc\",)")
+                },
+                StageEvent::End {
+                    stage: parse,
+                    output: some("3")
+                },
+                StageEvent::Start {
+                    stage: square,
+                    input: None
+                },
+                StageEvent::End {
+                    stage: square,
+                    output: None
+                },
+                StageEvent::Start {
+                    stage: unwrap,
+                    input: None
+                },
+                StageEvent::End {
+                    stage: unwrap,
+                    output: None
+                },
+                StageEvent::Start {
+                    stage: increment,
+                    input: some("(9,)")
+                },
+                StageEvent::End {
+                    stage: increment,
+                    output: some("10")
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn debugs_what_implements_it() {
+        let pipe = instrumented!(
+            CollectingObserver::new();
+            named("parse", |s: &str| s.len()),
+            |n: usize| Opaque(n.pow(2)),
+            |o: Opaque| o.0,
+            |n| Opaque(n),
+            |o: Opaque| o,
+        );
+        assert_eq!(pipe.run(("abc",)).0, 9);
+
+        let formatted = |events: Vec<StageEvent>| {
+            events
+                .into_iter()
+                .map(|event| match event {
+                    StageEvent::Start { input, .. } => input,
+                    StageEvent::End { output, .. } => output,
+                })
+                .collect::<Vec<_>>()
+        };
+        let some = |s: &str| Some(s.to_owned());
+        assert_eq!(
+            formatted(pipe.observer().take_events()),
+            [
+                some("(\"abc\",)"),
+                some("3"),
+                some("(3,)"),
+                None,
+                None,
+                some("9"),
+                some("(9,)"),
+                None,
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn shares_observers_between_pipes() {
+        let observer = CollectingObserver::new();
+        let mut calls = 0_u32;
+        let mut counting = Instrumented::new(
+            (|x: u32| {
+                calls = calls.saturating_add(1);
+                x
+            },),
+            &observer,
+        );
+        assert_eq!(counting.run_mut((1,)), 1);
+        assert_eq!(pipe![|x: u32| x].instrument(&observer).run_once((2,)), 2);
+        assert_eq!(observer.events().len(), 4);
+    }
+
+    #[test]
+    fn times_every_stage() {
+        let timing = TimingObserver::new();
+        let pipe = pipe![
+            |x: u32| x,
+            named("nested", piped(pipe![|x: u32| x].instrument(&timing)))
+        ]
+        .instrument(&timing);
+        assert_eq!(pipe.run((1,)), 1);
+
+        let timings = timing.take_timings();
+        let names = timings.iter().map(|t| t.stage.name).collect::<Vec<_>>();
+        assert_eq!(names, vec![None, None, Some("nested")]);
+        let elapsed = |i: usize| timings.get(i).map(|t| t.elapsed);
+        assert!(elapsed(1) <= elapsed(2));
+    }
+}
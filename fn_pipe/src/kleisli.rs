@@ -0,0 +1,105 @@
+//! Monadic pipes, where every stage is a Kleisli arrow `A -> M<B>` for some fixed `hkts` type
+//! constructor `M`, and the stages are chained together by binding each output into the next.
+//!
+//! Unlike the `Fn/Pipe*` variants, these are not supertraits of one another, because the `Bind*`
+//! hierarchy runs in the opposite direction to the `Fn*` hierarchy.
+
+use fn_pipe_proc::impl_kleisli_pipe_for_tuple;
+use hkts::family_pattern::typeclasses::bind::{Bind, BindMut, BindOnce};
+use hkts::family_pattern::typeclasses::pure::Pure;
+use hkts::family_pattern::{ConstructableTy1, ConstructableTyExt1 as _};
+
+/// A trait representing a pipe of Kleisli arrows, where the output of one will be bound into
+/// another with [`Bind::bind`], until the entire pipe ran
+pub trait KleisliPipe<M: Bind, A> {
+    type Output;
+
+    fn run_kleisli(&self, a: A) -> M::TC1<Self::Output>;
+}
+
+pub trait KleisliMutPipe<M: BindMut, A> {
+    type Output;
+
+    fn run_kleisli_mut(&mut self, a: A) -> M::TC1<Self::Output>;
+}
+
+pub trait KleisliOncePipe<M: BindOnce, A> {
+    type Output;
+
+    fn run_kleisli_once(self, a: A) -> M::TC1<Self::Output>;
+}
+
+// implement `Kleisli*Pipe` variants for tuples of upto length 26, to match the `Fn/Pipe*` variants
+impl_kleisli_pipe_for_tuple!(26usize);
+
+#[cfg(test)]
+mod tests {
+    use crate::kleisli::{KleisliMutPipe, KleisliOncePipe, KleisliPipe};
+    use hkts::family_pattern::data::option::OptionConstructor;
+    use hkts::family_pattern::data::vec::VecConstructor;
+
+    #[test]
+    fn binds_every_stage() {
+        let pipe = (
+            |s: &str| s.parse::<u32>().ok(),
+            |n: u32| n.checked_sub(1),
+            |n: u32| Some(n.to_string()),
+        );
+        assert_eq!(
+            KleisliPipe::<OptionConstructor, _>::run_kleisli(&pipe, "3"),
+            Some("2".to_owned())
+        );
+        assert_eq!(
+            KleisliPipe::<OptionConstructor, _>::run_kleisli(&pipe, "0"),
+            None
+        );
+        assert_eq!(
+            KleisliPipe::<OptionConstructor, _>::run_kleisli(&(), 5),
+            Some(5)
+        );
+
+        let mut seen = Vec::new();
+        let mut counting = (
+            |n: u32| n.checked_mul(2),
+            |n: u32| {
+                seen.push(n);
+                Some(n)
+            },
+        );
+        assert_eq!(
+            KleisliMutPipe::<OptionConstructor, _>::run_kleisli_mut(&mut counting, 4),
+            Some(8)
+        );
+        assert_eq!(seen, [8]);
+    }
+
+    #[test]
+    fn binds_every_branch() {
+        let pipe = (
+            |n: u32| (1..=n).collect::<Vec<_>>(),
+            |n: u32| vec![n, n.saturating_mul(10)],
+            |n: u32| if n > 5 { vec![] } else { vec![n] },
+        );
+        assert_eq!(
+            KleisliPipe::<VecConstructor, _>::run_kleisli(&pipe, 3),
+            [1, 2, 3]
+        );
+        assert_eq!(
+            KleisliPipe::<VecConstructor, _>::run_kleisli(&pipe, 0),
+            Vec::<u32>::new()
+        );
+    }
+
+    #[test]
+    fn binds_once() {
+        let suffix = "!".to_owned();
+        let pipe = (
+            |s: String| s.strip_prefix('+').map(str::to_owned),
+            move |s: String| Some(format!("{s}{suffix}")),
+        );
+        assert_eq!(
+            KleisliOncePipe::<OptionConstructor, _>::run_kleisli_once(pipe, "+hi".to_owned()),
+            Some("hi!".to_owned())
+        );
+    }
+}
@@ -2,28 +2,83 @@
 //! [unboxed_closures](https://doc.rust-lang.org/beta/unstable-book/language-features/unboxed-closures.html)
 //! and writing proc-macros.
 
-// enable Rust-unstable features for convenience
-#![feature(tuple_trait)]
-#![feature(unboxed_closures)]
-#![feature(fn_traits)]
-#![feature(unsized_fn_params)] // this is fine because I am PURELY wrapping around existing `Fn*` traits
+// enable Rust-unstable features for convenience, unless only the stable-Rust flavour is built
+#![cfg_attr(feature = "nightly", feature(tuple_trait))]
+#![cfg_attr(feature = "nightly", feature(unboxed_closures))]
+#![cfg_attr(feature = "nightly", feature(fn_traits))]
+#![cfg_attr(feature = "nightly", feature(unsized_fn_params))] // this is fine because I am PURELY wrapping around existing `Fn*` traits
+#![cfg_attr(feature = "nightly", feature(try_trait_v2))]
 // global lints
-#![allow(internal_features)]
+#![cfg_attr(feature = "nightly", allow(internal_features))]
 #![allow(clippy::arbitrary_source_item_ordering)]
 
+#[cfg(feature = "nightly")]
+pub mod adapt;
+#[cfg(feature = "nightly")]
+pub mod arrow;
+#[cfg(feature = "nightly")]
+pub mod async_pipe;
+pub mod builder;
+pub mod dyn_pipe;
+#[cfg(feature = "hlist")]
+pub mod hlist;
+#[cfg(feature = "nightly")]
+pub mod instrument;
+#[cfg(feature = "kleisli")]
+pub mod kleisli;
+#[cfg(feature = "nightly")]
+pub mod piped;
+#[cfg(feature = "nightly")]
+pub mod splat;
+#[cfg(feature = "stable")]
+pub mod stable;
+#[cfg(feature = "nightly")]
+pub mod try_pipe;
+
+#[cfg(feature = "nightly")]
+pub use adapt::{ContramapInput, Dimap, MapOutput, PipeExt};
+#[cfg(feature = "nightly")]
+pub use arrow::{Fanout, First, Second, Split, fanout, first, second, split};
+#[cfg(feature = "nightly")]
+pub use async_pipe::{AsyncFnMutPipe, AsyncFnOncePipe, AsyncFnPipe};
+pub use builder::Pipe;
+pub use dyn_pipe::{DynPipe, DynPipeError, DynStage, StageRegistry};
+#[cfg(feature = "hlist")]
+pub use hlist::{IntoHList, IntoTuple};
+#[cfg(feature = "nightly")]
+pub use instrument::{
+    Debugged, DebuggedInput, DebuggedOutput, Instrumented, Named, PipeObserver, Stage, StageCount,
+    StageMut, StageOnce, debugged, named,
+};
+#[cfg(feature = "kleisli")]
+pub use kleisli::{KleisliMutPipe, KleisliOncePipe, KleisliPipe};
+#[cfg(feature = "nightly")]
+pub use piped::{Piped, piped};
+#[cfg(feature = "nightly")]
+pub use splat::{Splat, splat};
+#[cfg(feature = "stable")]
+pub use stable::{TupleFn, TupleFnMut, TupleFnOnce};
+#[cfg(feature = "nightly")]
+pub use try_pipe::{TryFnMutPipe, TryFnOncePipe, TryFnPipe};
+
+#[cfg(feature = "nightly")]
 use fn_pipe_proc::impl_fn_pipe_for_tuple;
+#[cfg(feature = "nightly")]
 use std::marker::Tuple;
 
 /// A trait representing a pipe of functions, where the output of one will
 /// be fed as the input of another, until the entire pipe ran
+#[cfg(feature = "nightly")]
 pub trait FnPipe<Args: Tuple>: FnMutPipe<Args> {
     extern "rust-call" fn run(&self, args: Args) -> Self::Output;
 }
 
+#[cfg(feature = "nightly")]
 pub trait FnMutPipe<Args: Tuple>: FnOncePipe<Args> {
     extern "rust-call" fn run_mut(&mut self, args: Args) -> Self::Output;
 }
 
+#[cfg(feature = "nightly")]
 pub trait FnOncePipe<Args: Tuple> {
     type Output;
 
@@ -32,4 +87,5 @@ pub trait FnOncePipe<Args: Tuple> {
 
 // implement `Fn/Pipe*` variants for tuples of upto length 26,
 // can be increased in the future
+#[cfg(feature = "nightly")]
 impl_fn_pipe_for_tuple!(26usize);
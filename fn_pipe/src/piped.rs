@@ -0,0 +1,100 @@
+//! Adapting pipes into ordinary closures, so they can be passed anywhere an `Fn*` implementor is
+//! expected, including as a stage of another pipe.
+
+use crate::{FnMutPipe, FnOncePipe, FnPipe};
+use std::marker::Tuple;
+
+/// A wrapper which makes any pipe callable as an ordinary closure, with the same `Fn*` capability
+/// as the `Fn/Pipe*` variants that the wrapped pipe implements.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[repr(transparent)]
+pub struct Piped<P>(pub P);
+
+/// Wraps the pipe as a [`Piped`] closure.
+#[inline]
+pub const fn piped<P>(pipe: P) -> Piped<P> {
+    Piped(pipe)
+}
+
+impl<Args: Tuple, P: FnOncePipe<Args>> FnOnce<Args> for Piped<P> {
+    type Output = P::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: Args) -> Self::Output {
+        self.0.run_once(args)
+    }
+}
+
+impl<Args: Tuple, P: FnMutPipe<Args>> FnMut<Args> for Piped<P> {
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: Args) -> Self::Output {
+        self.0.run_mut(args)
+    }
+}
+
+impl<Args: Tuple, P: FnPipe<Args>> Fn<Args> for Piped<P> {
+    #[inline]
+    extern "rust-call" fn call(&self, args: Args) -> Self::Output {
+        self.0.run(args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FnPipe as _;
+    use crate::piped::{Piped, piped};
+
+    fn inc(n: u32) -> u32 {
+        n.saturating_add(1)
+    }
+
+    fn double(n: u32) -> u32 {
+        n.saturating_mul(2)
+    }
+
+    fn show(n: u32) -> String {
+        n.to_string()
+    }
+
+    #[test]
+    fn maps_iterators() {
+        let pipe = piped((inc, double));
+        assert_eq!((1..=3).map(pipe).collect::<Vec<_>>(), [4, 6, 8]);
+
+        let mut seen = Vec::new();
+        let counting = piped((inc, |n: u32| {
+            seen.push(n);
+            n
+        }));
+        assert_eq!((1..=2).map(counting).collect::<Vec<_>>(), [2, 3]);
+        assert_eq!(seen, [2, 3]);
+
+        let suffix = "!".to_owned();
+        let shout = piped((inc, move |n: u32| format!("{n}{suffix}")));
+        assert_eq!(shout(1), "2!");
+    }
+
+    #[cfg(feature = "kleisli")]
+    #[test]
+    fn maps_functors() {
+        use hkts::family_pattern::data::option::OptionConstructor;
+        use hkts::family_pattern::data::vec::VecConstructor;
+        use hkts::family_pattern::typeclasses::functor::{Functor as _, FunctorOnce as _};
+
+        assert_eq!(
+            VecConstructor::fmap(vec![1, 2], piped((inc, double))),
+            [4, 6]
+        );
+        assert_eq!(
+            OptionConstructor::fmap_once(Some(1), piped((double, show))),
+            Some("2".to_owned())
+        );
+    }
+
+    #[test]
+    fn nests_in_pipes() {
+        let inner = Piped((inc, double));
+        assert_eq!((inner, inner, inc).run((1,)), 11);
+        assert_eq!((double, piped((inner, inc))).run((1,)), 7);
+    }
+}
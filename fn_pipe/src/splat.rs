@@ -0,0 +1,71 @@
+//! Argument splatting between pipe stages, so that a stage producing a tuple can be followed by a
+//! stage taking each of those tuple elements as a separate argument.
+
+use std::marker::Tuple;
+
+/// A stage-wrapper which spreads its one tuple argument into the argument list of the wrapped
+/// function, e.g. `Splat(|x, y| x + y)` takes a single `(x, y)` argument.
+///
+/// Since it is itself an `Fn*` implementor, it can be used as a stage of any kind of pipe.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[repr(transparent)]
+pub struct Splat<F>(pub F);
+
+/// Wraps the function as a [`Splat`] stage.
+#[inline]
+pub const fn splat<F>(f: F) -> Splat<F> {
+    Splat(f)
+}
+
+impl<Args: Tuple, F: FnOnce<Args>> FnOnce<(Args,)> for Splat<F> {
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: (Args,)) -> Self::Output {
+        self.0.call_once(args.0)
+    }
+}
+
+impl<Args: Tuple, F: FnMut<Args>> FnMut<(Args,)> for Splat<F> {
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: (Args,)) -> Self::Output {
+        self.0.call_mut(args.0)
+    }
+}
+
+impl<Args: Tuple, F: Fn<Args>> Fn<(Args,)> for Splat<F> {
+    #[inline]
+    extern "rust-call" fn call(&self, args: (Args,)) -> Self::Output {
+        self.0.call(args.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::splat::splat;
+    use crate::{FnMutPipe as _, FnPipe as _};
+
+    #[test]
+    fn splats_pairs() {
+        let pipe = (
+            |n: u32| (n, n.saturating_add(1)),
+            splat(|x: u32, y: u32| x.saturating_mul(y)),
+        );
+        assert_eq!(pipe.run((3,)), 12);
+    }
+
+    #[test]
+    fn splats_triples() {
+        let mut seen = Vec::new();
+        let mut pipe = (
+            |s: &str| (s.len(), s.to_uppercase(), s.chars().next()),
+            splat(|len: usize, upper: String, first: Option<char>| {
+                seen.push(len);
+                format!("{upper}:{first:?}")
+            }),
+        );
+        assert_eq!(pipe.run_mut(("ab",)), "AB:Some('a')");
+        assert_eq!(pipe.run_mut(("",)), ":None");
+        assert_eq!(seen, [2, 0]);
+    }
+}
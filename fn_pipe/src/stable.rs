@@ -0,0 +1,116 @@
+//! The `Fn/Pipe*` variants for stable Rust, alongside the stand-ins for the `Fn*` traits which they
+//! are built on, taking all of their arguments as a single tuple value.
+//!
+//! They mirror the crate-root variants, and are called exactly like them, e.g. `pipe.run((x,))`;
+//! the `TupleFn*` traits are implemented for every closure of upto 12 arguments, so any ordinary
+//! function or closure can be used as a stage of a pipe.
+
+use fn_pipe_proc::{impl_fn_pipe_for_tuple, impl_tuple_fn_for_closures};
+
+/// A trait representing a pipe of functions, where the output of one will
+/// be fed as the input of another, until the entire pipe ran
+///
+/// This is the stable-Rust flavour, which takes its arguments as a tuple value
+pub trait FnPipe<Args>: FnMutPipe<Args> {
+    fn run(&self, args: Args) -> Self::Output;
+}
+
+pub trait FnMutPipe<Args>: FnOncePipe<Args> {
+    fn run_mut(&mut self, args: Args) -> Self::Output;
+}
+
+pub trait FnOncePipe<Args> {
+    type Output;
+
+    fn run_once(self, args: Args) -> Self::Output;
+}
+
+/// The stable-Rust stand-in for [`Fn`], taking its arguments as a tuple value.
+pub trait TupleFn<Args>: TupleFnMut<Args> {
+    fn call_tuple(&self, args: Args) -> Self::Output;
+}
+
+/// The stable-Rust stand-in for [`FnMut`], taking its arguments as a tuple value.
+pub trait TupleFnMut<Args>: TupleFnOnce<Args> {
+    fn call_tuple_mut(&mut self, args: Args) -> Self::Output;
+}
+
+/// The stable-Rust stand-in for [`FnOnce`], taking its arguments as a tuple value.
+pub trait TupleFnOnce<Args> {
+    type Output;
+
+    fn call_tuple_once(self, args: Args) -> Self::Output;
+}
+
+// implement the stable `Fn/Pipe*` variants for tuples of upto length 26, to match the crate-root
+// variants
+impl_fn_pipe_for_tuple!(26usize, stable);
+
+// implement `TupleFn*` variants for closures of upto 12 arguments, to match the standard library's
+// tuple trait implementations
+impl_tuple_fn_for_closures!(12usize);
+
+#[cfg(test)]
+mod tests {
+    use crate::pipe;
+    use crate::stable::{
+        FnMutPipe as _, FnOncePipe as _, FnPipe as _, TupleFn as _, TupleFnMut as _,
+        TupleFnOnce as _,
+    };
+
+    #[test]
+    fn calls_closures_like_fn() {
+        let add = |x: u32, y: u32| x.saturating_add(y);
+        assert_eq!(add.call_tuple((1, 2)), add(1, 2));
+        assert_eq!((|| 7_u32).call_tuple(()), 7);
+
+        let mut calls = Vec::new();
+        let mut push = |x: u32| calls.push(x);
+        push.call_tuple_mut((1,));
+        push(2);
+        assert_eq!(calls, [1, 2]);
+
+        let owned = "abc".to_owned();
+        let consume = move |suffix: &str| format!("{owned}{suffix}");
+        assert_eq!(consume.call_tuple_once(("d",)), "abcd");
+    }
+
+    #[test]
+    fn runs_pipes_like_fn() {
+        let parse = |s: &str| s.parse::<u32>().unwrap_or_default();
+        let double = |n: u32| n.saturating_mul(2);
+        let show = |n: u32| n.to_string();
+
+        let pipe = (parse, double, show);
+        assert_eq!(pipe.run(("21",)), show(double(parse("21"))));
+        assert_eq!(
+            pipe![parse, double, double].run(("x",)),
+            double(double(parse("x")))
+        );
+
+        let mut seen = Vec::new();
+        let mut tracing = (double, |n: u32| {
+            seen.push(n);
+            n
+        });
+        assert_eq!(tracing.run_mut((3,)), 6);
+        assert_eq!(tracing.run_once((4,)), 8);
+        assert_eq!(seen, [6, 8]);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn runs_alongside_the_nightly_flavour() {
+        use crate::{Pipe, stable};
+
+        let pipe = pipe![|n: u32| n.saturating_mul(2), |n: u32| n.to_string()];
+        assert_eq!(
+            stable::FnPipe::run(&pipe, (21,)),
+            crate::FnPipe::run(&pipe, (21,))
+        );
+        assert_eq!(
+            stable::FnPipe::run(&(pipe, Pipe::new().then(|s: String| s.len())), (21,)),
+            2
+        );
+    }
+}
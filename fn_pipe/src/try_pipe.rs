@@ -0,0 +1,77 @@
+//! Fallible pipes, where every stage produces a [`Try`] value whose unwrapped output is fed into
+//! the next stage, and the first residual encountered short-circuits the rest of the pipe.
+
+use fn_pipe_proc::impl_try_fn_pipe_for_tuple;
+use std::marker::Tuple;
+use std::ops::{FromResidual, Try};
+
+/// A trait representing a pipe of fallible functions, where the unwrapped output of one will
+/// be fed as the input of another, until either the entire pipe ran or one of them short-circuited
+pub trait TryFnPipe<Args: Tuple>: TryFnMutPipe<Args> {
+    extern "rust-call" fn try_run(&self, args: Args) -> Self::Output;
+}
+
+pub trait TryFnMutPipe<Args: Tuple>: TryFnOncePipe<Args> {
+    extern "rust-call" fn try_run_mut(&mut self, args: Args) -> Self::Output;
+}
+
+pub trait TryFnOncePipe<Args: Tuple> {
+    /// The output of the last stage, which every short-circuited residual is converted into.
+    type Output: Try;
+
+    extern "rust-call" fn try_run_once(self, args: Args) -> Self::Output;
+}
+
+// implement `TryFn/Pipe*` variants for tuples of upto length 26, to match the `Fn/Pipe*` variants
+impl_try_fn_pipe_for_tuple!(26usize);
+
+#[cfg(test)]
+mod tests {
+    use crate::try_pipe::{TryFnMutPipe as _, TryFnOncePipe as _, TryFnPipe as _};
+    use std::cell::Cell;
+
+    #[test]
+    fn option_pipe_short_circuits() {
+        let later = Cell::new(0_u32);
+        let pipe = (
+            |s: &str| s.parse::<u32>().ok(),
+            |n: u32| n.checked_sub(1),
+            |n: u32| {
+                later.set(later.get().saturating_add(1));
+                Some(n.to_string())
+            },
+        );
+        assert_eq!(pipe.try_run(("3",)), Some("2".to_owned()));
+        assert_eq!(later.get(), 1);
+        assert_eq!(pipe.try_run(("x",)), None);
+        assert_eq!(pipe.try_run(("0",)), None);
+        assert_eq!(later.get(), 1);
+    }
+
+    #[test]
+    fn result_pipe_short_circuits() {
+        let mut calls = Vec::new();
+        let mut pipe = (
+            |s: &str| s.parse::<i32>().map_err(|err| err.to_string()),
+            |n: i32| {
+                if n < 0 {
+                    Err(format!("{n} is negative"))
+                } else {
+                    Ok(n.unsigned_abs())
+                }
+            },
+            |n: u32| -> Result<u32, String> {
+                calls.push(n);
+                Ok(n.saturating_mul(2))
+            },
+        );
+        assert_eq!(pipe.try_run_mut(("21",)), Ok(42));
+        assert_eq!(pipe.try_run_mut(("-1",)), Err("-1 is negative".to_owned()));
+        assert_eq!(
+            pipe.try_run_mut(("",)),
+            Err("cannot parse integer from empty string".to_owned())
+        );
+        assert_eq!(pipe.try_run_once(("4",)), Ok(8));
+        assert_eq!(calls, [21, 4]);
+    }
+}
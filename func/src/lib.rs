@@ -1,6 +1,549 @@
+//! A toolkit of general-purpose function combinators, shared across the workspace.
+//!
+//! Combinators that wrap other functions come in `Fn`, `FnMut` and `FnOnce` flavours, where the
+//! `_mut` and `_once` suffixes name the flavour of both the wrapped and the produced functions. The
+//! plain flavour produces `Fn` implementors, so they can be passed directly to e.g. `fmap` and
+//! `bind`.
+
+// enable Rust-unstable features for convenience
+#![feature(impl_trait_in_fn_trait_return)]
+#![feature(unboxed_closures)]
+#![feature(fn_traits)]
+
+pub mod memo;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub use memo::{
+    Cache, CacheStats, HashMapCache, LruCache, Memoized, MemoizedFix, SyncCache, memoize,
+    memoize_fix, memoize_fix_with, memoize_with,
+};
+
+// Constant and identity -------------------------------------------------------------------------
+
 /// Create a constant function which produces the same constant value for every argument passed to it.
 #[allow(clippy::inline_always)]
 #[inline(always)]
 pub const fn constant<A: Clone, B>(constant: A) -> impl Fn(B) -> A {
     move |_| constant.clone()
 }
+
+/// The identity function, which returns its argument unchanged.
+///
+/// Being an ordinary function, it is already an `Fn`, `FnMut` and `FnOnce` implementor all at once.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn identity<A>(a: A) -> A {
+    a
+}
+
+// Flip -------------------------------------------------------------------------------------------
+
+/// Create a function which is `f` with the order of its two arguments swapped.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn flip<A, B, C, F: Fn(A, B) -> C>(f: F) -> impl Fn(B, A) -> C {
+    move |b, a| f(a, b)
+}
+
+/// Create a function which is `f` with the order of its two arguments swapped.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn flip_mut<A, B, C, F: FnMut(A, B) -> C>(mut f: F) -> impl FnMut(B, A) -> C {
+    move |b, a| f(a, b)
+}
+
+/// Create a function which is `f` with the order of its two arguments swapped.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn flip_once<A, B, C, F: FnOnce(A, B) -> C>(f: F) -> impl FnOnce(B, A) -> C {
+    move |b, a| f(a, b)
+}
+
+// On ---------------------------------------------------------------------------------------------
+
+/// Create a function which applies `g` to both of its arguments, and combines the results with `f`.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn on<A, B, C, F: Fn(B, B) -> C, G: Fn(A) -> B>(f: F, g: G) -> impl Fn(A, A) -> C {
+    move |x, y| f(g(x), g(y))
+}
+
+/// Create a function which applies `g` to both of its arguments, and combines the results with `f`.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn on_mut<A, B, C, F: FnMut(B, B) -> C, G: FnMut(A) -> B>(
+    mut f: F,
+    mut g: G,
+) -> impl FnMut(A, A) -> C {
+    move |x, y| {
+        let (gx, gy) = (g(x), g(y));
+        f(gx, gy)
+    }
+}
+
+/// Create a function which applies `g` to both of its arguments, and combines the results with `f`.
+///
+/// The function `g` still needs to be `FnMut`, because it is applied twice.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn on_once<A, B, C, F: FnOnce(B, B) -> C, G: FnMut(A) -> B>(
+    f: F,
+    mut g: G,
+) -> impl FnOnce(A, A) -> C {
+    move |x, y| {
+        let (gx, gy) = (g(x), g(y));
+        f(gx, gy)
+    }
+}
+
+// Composition ------------------------------------------------------------------------------------
+
+/// Create the composition `f . g`, i.e. a function which applies `g` and then `f`.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn compose<A, B, C, F: Fn(B) -> C, G: Fn(A) -> B>(f: F, g: G) -> impl Fn(A) -> C {
+    move |a| f(g(a))
+}
+
+/// Create the composition `f . g`, i.e. a function which applies `g` and then `f`.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn compose_mut<A, B, C, F: FnMut(B) -> C, G: FnMut(A) -> B>(
+    mut f: F,
+    mut g: G,
+) -> impl FnMut(A) -> C {
+    move |a| f(g(a))
+}
+
+/// Create the composition `f . g`, i.e. a function which applies `g` and then `f`.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn compose_once<A, B, C, F: FnOnce(B) -> C, G: FnOnce(A) -> B>(
+    f: F,
+    g: G,
+) -> impl FnOnce(A) -> C {
+    move |a| f(g(a))
+}
+
+/// Create the composition `g . f`, i.e. a function which applies `f` and then `g`.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn and_then<A, B, C, F: Fn(A) -> B, G: Fn(B) -> C>(f: F, g: G) -> impl Fn(A) -> C {
+    move |a| g(f(a))
+}
+
+/// Create the composition `g . f`, i.e. a function which applies `f` and then `g`.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn and_then_mut<A, B, C, F: FnMut(A) -> B, G: FnMut(B) -> C>(
+    mut f: F,
+    mut g: G,
+) -> impl FnMut(A) -> C {
+    move |a| g(f(a))
+}
+
+/// Create the composition `g . f`, i.e. a function which applies `f` and then `g`.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn and_then_once<A, B, C, F: FnOnce(A) -> B, G: FnOnce(B) -> C>(
+    f: F,
+    g: G,
+) -> impl FnOnce(A) -> C {
+    move |a| g(f(a))
+}
+
+// Application ------------------------------------------------------------------------------------
+
+/// Create a function which applies whichever function it is given to `a`, i.e. `($ a)`.
+///
+/// There is no separate `FnMut` flavour, because the produced function never mutates `a`.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn apply<A: Clone, B, F: FnOnce(A) -> B>(a: A) -> impl Fn(F) -> B {
+    move |f| f(a.clone())
+}
+
+/// Create a function which applies whichever function it is given to `a`, i.e. `($ a)`.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn apply_once<A, B, F: FnOnce(A) -> B>(a: A) -> impl FnOnce(F) -> B {
+    move |f| f(a)
+}
+
+// Tap --------------------------------------------------------------------------------------------
+
+/// Create a function which passes a reference to its argument to `f`, e.g. for logging, and then
+/// returns the argument unchanged.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn tap<A, F: Fn(&A)>(f: F) -> impl Fn(A) -> A {
+    move |a| {
+        f(&a);
+        a
+    }
+}
+
+/// Create a function which passes a reference to its argument to `f`, e.g. for logging, and then
+/// returns the argument unchanged.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn tap_mut<A, F: FnMut(&A)>(mut f: F) -> impl FnMut(A) -> A {
+    move |a| {
+        f(&a);
+        a
+    }
+}
+
+/// Create a function which passes a reference to its argument to `f`, e.g. for logging, and then
+/// returns the argument unchanged.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn tap_once<A, F: FnOnce(&A)>(f: F) -> impl FnOnce(A) -> A {
+    move |a| {
+        f(&a);
+        a
+    }
+}
+
+// Currying ---------------------------------------------------------------------------------------
+
+/// Create the curried form of `f`, which takes the halves of the tuple one at a time.
+///
+/// Both `f` and the first argument get cloned, so that each partial application stands alone.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn curry<A: Clone, B, C, F: Fn((A, B)) -> C + Clone>(
+    f: F,
+) -> impl Fn(A) -> impl Fn(B) -> C {
+    move |a| {
+        let f = f.clone();
+        move |b| f((a.clone(), b))
+    }
+}
+
+/// Create the curried form of `f`, which takes the halves of the tuple one at a time.
+///
+/// Every partial application shares `f`, so that the changes it makes to its captured state carry
+/// over between all of them, whereas the first argument gets cloned. Hence the partial
+/// applications must not call one another from within `f`.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub fn curry_mut<A: Clone, B, C, F: FnMut((A, B)) -> C>(
+    f: F,
+) -> impl FnMut(A) -> impl FnMut(B) -> C {
+    let f = Rc::new(RefCell::new(f));
+    move |a| {
+        let f = Rc::clone(&f);
+        move |b| (f.borrow_mut())((a.clone(), b))
+    }
+}
+
+/// Create the curried form of `f`, which takes the halves of the tuple one at a time.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn curry_once<A, B, C, F: FnOnce((A, B)) -> C>(
+    f: F,
+) -> impl FnOnce(A) -> impl FnOnce(B) -> C {
+    move |a| move |b| f((a, b))
+}
+
+/// Create the uncurried form of `f`, which takes both of its arguments as a single tuple.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn uncurry<A, B, C, F: Fn(A, B) -> C>(f: F) -> impl Fn((A, B)) -> C {
+    move |(a, b)| f(a, b)
+}
+
+/// Create the uncurried form of `f`, which takes both of its arguments as a single tuple.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn uncurry_mut<A, B, C, F: FnMut(A, B) -> C>(mut f: F) -> impl FnMut((A, B)) -> C {
+    move |(a, b)| f(a, b)
+}
+
+/// Create the uncurried form of `f`, which takes both of its arguments as a single tuple.
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub const fn uncurry_once<A, B, C, F: FnOnce(A, B) -> C>(f: F) -> impl FnOnce((A, B)) -> C {
+    move |(a, b)| f(a, b)
+}
+
+// Fixed-point ------------------------------------------------------------------------------------
+
+/// The fixed-point combinator, which creates a recursive function out of `f`.
+///
+/// It works by passing `f` a reference to the recursive function itself alongside its argument,
+/// e.g. `fix(|fact, n: u64| if n == 0 { 1 } else { n * fact(n - 1) })`.
+///
+/// There are no `FnMut` or `FnOnce` flavours, because `f` needs to be re-entered while it is
+/// already running; the produced function is `Fn` though, so it can be used as either.
+#[inline]
+pub fn fix<A, B, F: Fn(&dyn Fn(A) -> B, A) -> B>(f: F) -> impl Fn(A) -> B {
+    #[inline]
+    fn fixed<A, B, F: Fn(&dyn Fn(A) -> B, A) -> B>(f: &F, a: A) -> B {
+        f(&|inner| fixed(f, inner), a)
+    }
+
+    move |a| fixed(&f, a)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        and_then, and_then_mut, and_then_once, apply, apply_once, compose, compose_mut,
+        compose_once, constant, curry, curry_mut, curry_once, fix, flip, flip_mut, flip_once,
+        identity, on, on_mut, on_once, tap, tap_mut, tap_once, uncurry, uncurry_mut, uncurry_once,
+    };
+    use std::cell::Cell;
+
+    fn double(n: u32) -> u32 {
+        n.saturating_mul(2)
+    }
+
+    fn succ(n: u32) -> u32 {
+        n.saturating_add(1)
+    }
+
+    #[test]
+    fn identity_and_constant() {
+        assert_eq!(
+            vec![1, 2].into_iter().map(identity).collect::<Vec<_>>(),
+            [1, 2]
+        );
+        assert_eq!(identity(1), 1);
+        assert_eq!(
+            vec![1, 2]
+                .into_iter()
+                .map(constant::<_, u32>("x"))
+                .collect::<Vec<_>>(),
+            ["x", "x"]
+        );
+    }
+
+    #[test]
+    fn flips() {
+        let pairs = vec![(1_u32, 10_u32), (2, 20)];
+        assert_eq!(
+            pairs
+                .clone()
+                .into_iter()
+                .map(uncurry(flip(u32::saturating_sub)))
+                .collect::<Vec<_>>(),
+            [9, 18]
+        );
+
+        let mut calls = 0_u32;
+        let counted = flip_mut(|a: u32, b: u32| {
+            calls = calls.saturating_add(1);
+            a.saturating_sub(b)
+        });
+        assert_eq!(
+            pairs
+                .into_iter()
+                .map(uncurry_mut(counted))
+                .collect::<Vec<_>>(),
+            [9, 18]
+        );
+        assert_eq!(calls, 2);
+
+        let prefix = "a".to_owned();
+        let joined = flip_once(move |s: String, t: String| [prefix, s, t].concat());
+        assert_eq!(
+            Some(("c".to_owned(), "b".to_owned())).map(uncurry_once(joined)),
+            Some("abc".to_owned())
+        );
+    }
+
+    #[test]
+    fn ons() {
+        let pairs = vec![("a", "bc"), ("def", "")];
+        assert_eq!(
+            pairs
+                .clone()
+                .into_iter()
+                .map(uncurry(on(usize::saturating_add, str::len)))
+                .collect::<Vec<_>>(),
+            [3, 3]
+        );
+
+        let mut seen = Vec::new();
+        let lengths = on_mut(
+            |x: usize, y: usize| x.max(y),
+            |s: &str| {
+                seen.push(s.to_owned());
+                s.len()
+            },
+        );
+        assert_eq!(
+            pairs
+                .into_iter()
+                .map(uncurry_mut(lengths))
+                .collect::<Vec<_>>(),
+            [2, 3]
+        );
+        assert_eq!(seen, ["a", "bc", "def", ""]);
+
+        let owned = vec![0_usize];
+        let pushed = on_once(
+            move |x: usize, y: usize| [owned, vec![x, y]].concat(),
+            str::len,
+        );
+        assert_eq!(
+            Some(("a", "bc")).map(uncurry_once(pushed)),
+            Some(vec![0, 1, 2])
+        );
+    }
+
+    #[test]
+    fn composes() {
+        assert_eq!(
+            vec![1, 2]
+                .into_iter()
+                .map(compose(double, succ))
+                .collect::<Vec<_>>(),
+            [4, 6]
+        );
+        assert_eq!(
+            vec![1, 2]
+                .into_iter()
+                .map(and_then(double, succ))
+                .collect::<Vec<_>>(),
+            [3, 5]
+        );
+        assert_eq!(
+            vec![1, 2]
+                .into_iter()
+                .flat_map(compose(|n: u32| vec![n, n], succ))
+                .collect::<Vec<_>>(),
+            [2, 2, 3, 3]
+        );
+
+        let mut trace = Vec::new();
+        let traced = compose_mut(
+            |n: u32| {
+                trace.push(n);
+                vec![n]
+            },
+            double,
+        );
+        assert_eq!(
+            vec![1, 2].into_iter().flat_map(traced).collect::<Vec<_>>(),
+            [2, 4]
+        );
+        assert_eq!(trace, [2, 4]);
+        let mut sum = 0_u32;
+        let summed = and_then_mut(succ, |n: u32| {
+            sum = sum.saturating_add(n);
+            sum
+        });
+        assert_eq!(
+            vec![1, 2].into_iter().map(summed).collect::<Vec<_>>(),
+            [2, 5]
+        );
+
+        let suffix = "!".to_owned();
+        let shout = and_then_once(
+            |n: u32| n.to_string(),
+            move |s: String| Some([s, suffix].concat()),
+        );
+        assert_eq!(Some(1).and_then(shout), Some("1!".to_owned()));
+        let wrap = compose_once(Some, |s: &str| s.to_uppercase());
+        assert_eq!(Some("a").and_then(wrap), Some("A".to_owned()));
+    }
+
+    #[test]
+    fn applies() {
+        let fs: Vec<fn(u32) -> u32> = vec![double, succ];
+        assert_eq!(fs.into_iter().map(apply(3)).collect::<Vec<_>>(), [6, 4]);
+
+        let owned = "a".to_owned();
+        assert_eq!(Some(|s: String| s.len()).map(apply_once(owned)), Some(1));
+    }
+
+    #[test]
+    fn taps() {
+        let tapped = Cell::new(0_u32);
+        let record = tap(|n: &u32| tapped.set(*n));
+        assert_eq!(
+            vec![1, 2].into_iter().map(record).collect::<Vec<_>>(),
+            [1, 2]
+        );
+        assert_eq!(tapped.get(), 2);
+
+        let mut seen = Vec::new();
+        assert_eq!(
+            vec![1, 2]
+                .into_iter()
+                .map(tap_mut(|n: &u32| seen.push(*n)))
+                .collect::<Vec<_>>(),
+            [1, 2]
+        );
+        assert_eq!(seen, [1, 2]);
+
+        let mut log = Vec::new();
+        let logged = tap_once(|n: &u32| log.push(*n));
+        assert_eq!(logged(3), 3);
+        assert_eq!(log, [3]);
+    }
+
+    #[test]
+    fn curries() {
+        let add = curry(|(a, b): (u32, u32)| a.saturating_add(b));
+        assert_eq!(
+            vec![1, 2]
+                .into_iter()
+                .flat_map(|a| vec![10, 20].into_iter().map(add(a)).collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            [11, 21, 12, 22]
+        );
+        assert_eq!(
+            vec![(1, 2)]
+                .into_iter()
+                .map(uncurry(|a: u32, b: u32| a.saturating_add(b)))
+                .collect::<Vec<_>>(),
+            [3]
+        );
+
+        // every partial application counts towards the same calls
+        let mut calls = 0_u32;
+        let mut counted = curry_mut(move |(a, b): (u32, u32)| {
+            calls = calls.saturating_add(1);
+            (calls, a.saturating_add(b))
+        });
+        let (mut from_one, mut from_two) = (counted(1), counted(2));
+        assert_eq!(from_one(10), (1, 11));
+        assert_eq!(
+            vec![10, 20]
+                .into_iter()
+                .map(&mut from_two)
+                .collect::<Vec<_>>(),
+            [(2, 12), (3, 22)]
+        );
+        assert_eq!(from_one(30), (4, 31));
+
+        let owned = "a".to_owned();
+        let concat = curry_once(move |(s, t): (String, &str)| [owned, s, t.to_owned()].concat());
+        assert_eq!(concat("b".to_owned())("c"), "abc");
+    }
+
+    #[test]
+    fn fixes() {
+        let factorial = fix(|fact: &dyn Fn(u64) -> u64, n: u64| {
+            if n == 0 {
+                1
+            } else {
+                n.saturating_mul(fact(n.saturating_sub(1)))
+            }
+        });
+        assert_eq!(
+            vec![0, 1, 5]
+                .into_iter()
+                .map(&factorial)
+                .collect::<Vec<_>>(),
+            [1, 1, 120]
+        );
+        assert_eq!(Some(3).map(&factorial), Some(6));
+    }
+}
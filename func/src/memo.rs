@@ -0,0 +1,524 @@
+//! Memoization of functions, where every result is cached by its argument so that repeated calls
+//! with the same argument only compute the result once.
+//!
+//! The cache backend is pluggable through the [`Cache`] trait, and the memoized functions keep
+//! count of their cache hits and misses.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+/// A trait for cache backends of memoized functions.
+///
+/// Both methods take `&self` because memoized functions are `Fn` implementors, meaning caches will
+/// need interior mutability.
+pub trait Cache {
+    /// The type of arguments that values are cached by.
+    type Key;
+    /// The type of values that are cached.
+    type Value;
+
+    /// Looks up the value cached for the key, if there is one.
+    fn get(&self, key: &Self::Key) -> Option<Self::Value>;
+
+    /// Caches the value for the key.
+    fn insert(&self, key: Self::Key, value: Self::Value);
+}
+
+/// An unbounded cache, which keeps every value it is given.
+#[derive(Debug)]
+pub struct HashMapCache<K, V>(RefCell<HashMap<K, V>>);
+
+impl<K, V> HashMapCache<K, V> {
+    /// Creates an empty cache.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self(RefCell::new(HashMap::new()))
+    }
+
+    /// The number of values in the cache.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    /// Whether the cache is empty.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+}
+
+impl<K, V> Default for HashMapCache<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Cache for HashMapCache<K, V> {
+    type Key = K;
+    type Value = V;
+
+    #[inline]
+    fn get(&self, key: &K) -> Option<V> {
+        self.0.borrow().get(key).cloned()
+    }
+
+    #[inline]
+    fn insert(&self, key: K, value: V) {
+        self.0.borrow_mut().insert(key, value);
+    }
+}
+
+/// The entries of an [`LruCache`], alongside the keys ordered by when they were last used.
+#[derive(Debug)]
+struct LruEntries<K, V> {
+    values: HashMap<K, (V, u64)>,
+    recency: BTreeMap<u64, K>,
+    clock: u64,
+}
+
+/// A cache holding at most `capacity` values, which evicts the least-recently used value to make
+/// room for new ones.
+#[derive(Debug)]
+pub struct LruCache<K, V> {
+    capacity: usize,
+    entries: RefCell<LruEntries<K, V>>,
+}
+
+impl<K, V> LruCache<K, V> {
+    /// Creates an empty cache, which holds at most `capacity` values.
+    #[inline]
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: RefCell::new(LruEntries {
+                values: HashMap::new(),
+                recency: BTreeMap::new(),
+                clock: 0,
+            }),
+        }
+    }
+
+    /// The maximum number of values in the cache.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of values in the cache.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.borrow().values.len()
+    }
+
+    /// Whether the cache is empty.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.borrow().values.is_empty()
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Cache for LruCache<K, V> {
+    type Key = K;
+    type Value = V;
+
+    #[inline]
+    fn get(&self, key: &K) -> Option<V> {
+        let mut entries = self.entries.borrow_mut();
+        let &mut LruEntries {
+            ref mut values,
+            ref mut recency,
+            ref mut clock,
+        } = &mut *entries;
+
+        // mark the value as the most-recently used one
+        let &mut (ref value, ref mut last_used) = values.get_mut(key)?;
+        recency.remove(last_used);
+        *clock = clock.wrapping_add(1);
+        *last_used = *clock;
+        recency.insert(*clock, key.clone());
+        Some(value.clone())
+    }
+
+    #[inline]
+    fn insert(&self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries.borrow_mut();
+        let &mut LruEntries {
+            ref mut values,
+            ref mut recency,
+            ref mut clock,
+        } = &mut *entries;
+
+        // make room by evicting the least-recently used value, unless the key is already cached
+        if !values.contains_key(&key)
+            && values.len() >= self.capacity
+            && let Some((_, evicted)) = recency.pop_first()
+        {
+            values.remove(&evicted);
+        }
+
+        *clock = clock.wrapping_add(1);
+        recency.insert(*clock, key.clone());
+        if let Some((_, last_used)) = values.insert(key, (value, *clock)) {
+            recency.remove(&last_used);
+        }
+    }
+}
+
+/// An unbounded cache which can be shared between threads, so that memoized functions using it
+/// are [`Sync`] (so long as the function itself is).
+#[derive(Debug)]
+pub struct SyncCache<K, V>(Mutex<HashMap<K, V>>);
+
+impl<K, V> SyncCache<K, V> {
+    /// Creates an empty cache.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Mutex::new(HashMap::new()))
+    }
+
+    /// The number of values in the cache.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner).len()
+    }
+
+    /// Whether the cache is empty.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_empty()
+    }
+}
+
+impl<K, V> Default for SyncCache<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// a poisoned cache is still a perfectly good cache, as values are only ever inserted whole
+impl<K: Hash + Eq, V: Clone> Cache for SyncCache<K, V> {
+    type Key = K;
+    type Value = V;
+
+    #[inline]
+    fn get(&self, key: &K) -> Option<V> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+            .cloned()
+    }
+
+    #[inline]
+    fn insert(&self, key: K, value: V) {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, value);
+    }
+}
+
+/// A snapshot of the cache hits and misses of a memoized function.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct CacheStats {
+    /// The number of calls whose result was found in the cache.
+    pub hits: usize,
+    /// The number of calls whose result had to be computed.
+    pub misses: usize,
+}
+
+/// The hit and miss counters of a memoized function.
+#[derive(Debug, Default)]
+struct Counters {
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+impl Counters {
+    #[inline]
+    fn hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    fn miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// A memoized function, see [`memoize`].
+#[derive(Debug)]
+pub struct Memoized<F, C> {
+    f: F,
+    cache: C,
+    counters: Counters,
+}
+
+/// A memoized recursive function, see [`memoize_fix`].
+#[derive(Debug)]
+pub struct MemoizedFix<F, C> {
+    body: F,
+    cache: C,
+    counters: Counters,
+}
+
+/// Memoize `f` with an unbounded [`HashMapCache`].
+#[inline]
+pub fn memoize<A, B, F>(f: F) -> Memoized<F, HashMapCache<A, B>>
+where
+    A: Hash + Eq + Clone,
+    B: Clone,
+    F: Fn(A) -> B,
+{
+    memoize_with(f, HashMapCache::new())
+}
+
+/// Memoize `f` with the given cache backend.
+#[inline]
+pub fn memoize_with<F: Fn(C::Key) -> C::Value, C: Cache>(f: F, cache: C) -> Memoized<F, C> {
+    Memoized {
+        f,
+        cache,
+        counters: Counters::default(),
+    }
+}
+
+/// Memoize a recursive function with an unbounded [`HashMapCache`], where `body` is passed the
+/// memoized function itself alongside its argument, so that its recursive calls hit the cache too.
+///
+/// This makes dynamic programming expressible as a closure, e.g.
+/// `memoize_fix(|fib, n: u64| if n < 2 { n } else { fib(n - 1) + fib(n - 2) })`.
+#[inline]
+pub fn memoize_fix<A, B, F>(body: F) -> MemoizedFix<F, HashMapCache<A, B>>
+where
+    A: Hash + Eq + Clone,
+    B: Clone,
+    F: Fn(&dyn Fn(A) -> B, A) -> B,
+{
+    memoize_fix_with(body, HashMapCache::new())
+}
+
+/// Memoize a recursive function with the given cache backend, see [`memoize_fix`].
+#[inline]
+pub fn memoize_fix_with<F, C>(body: F, cache: C) -> MemoizedFix<F, C>
+where
+    F: Fn(&dyn Fn(C::Key) -> C::Value, C::Key) -> C::Value,
+    C: Cache,
+{
+    MemoizedFix {
+        body,
+        cache,
+        counters: Counters::default(),
+    }
+}
+
+impl<F, C> Memoized<F, C> {
+    /// The cache hits and misses of this function so far.
+    #[inline]
+    pub fn stats(&self) -> CacheStats {
+        self.counters.stats()
+    }
+
+    /// The cache backend of this function.
+    #[inline]
+    pub const fn cache(&self) -> &C {
+        &self.cache
+    }
+}
+
+impl<F, C> MemoizedFix<F, C> {
+    /// The cache hits and misses of this function so far, including those of its recursive calls.
+    #[inline]
+    pub fn stats(&self) -> CacheStats {
+        self.counters.stats()
+    }
+
+    /// The cache backend of this function.
+    #[inline]
+    pub const fn cache(&self) -> &C {
+        &self.cache
+    }
+}
+
+// memoized functions are callable as ordinary closures, so that they can be passed anywhere an
+// `Fn*` implementor is expected
+mod memoized_impls {
+    use crate::memo::{Cache, Memoized};
+
+    impl<F, C> FnOnce<(C::Key,)> for Memoized<F, C>
+    where
+        F: Fn(C::Key) -> C::Value,
+        C: Cache<Key: Clone, Value: Clone>,
+    {
+        type Output = C::Value;
+
+        #[inline]
+        extern "rust-call" fn call_once(self, args: (C::Key,)) -> Self::Output {
+            self.call(args)
+        }
+    }
+
+    impl<F, C> FnMut<(C::Key,)> for Memoized<F, C>
+    where
+        F: Fn(C::Key) -> C::Value,
+        C: Cache<Key: Clone, Value: Clone>,
+    {
+        #[inline]
+        extern "rust-call" fn call_mut(&mut self, args: (C::Key,)) -> Self::Output {
+            self.call(args)
+        }
+    }
+
+    impl<F, C> Fn<(C::Key,)> for Memoized<F, C>
+    where
+        F: Fn(C::Key) -> C::Value,
+        C: Cache<Key: Clone, Value: Clone>,
+    {
+        #[inline]
+        extern "rust-call" fn call(&self, (key,): (C::Key,)) -> Self::Output {
+            if let Some(value) = self.cache.get(&key) {
+                self.counters.hit();
+                return value;
+            }
+            self.counters.miss();
+            let value = (self.f)(key.clone());
+            self.cache.insert(key, value.clone());
+            value
+        }
+    }
+}
+
+mod memoized_fix_impls {
+    use crate::memo::{Cache, MemoizedFix};
+
+    impl<F, C> FnOnce<(C::Key,)> for MemoizedFix<F, C>
+    where
+        F: Fn(&dyn Fn(C::Key) -> C::Value, C::Key) -> C::Value,
+        C: Cache<Key: Clone, Value: Clone>,
+    {
+        type Output = C::Value;
+
+        #[inline]
+        extern "rust-call" fn call_once(self, args: (C::Key,)) -> Self::Output {
+            self.call(args)
+        }
+    }
+
+    impl<F, C> FnMut<(C::Key,)> for MemoizedFix<F, C>
+    where
+        F: Fn(&dyn Fn(C::Key) -> C::Value, C::Key) -> C::Value,
+        C: Cache<Key: Clone, Value: Clone>,
+    {
+        #[inline]
+        extern "rust-call" fn call_mut(&mut self, args: (C::Key,)) -> Self::Output {
+            self.call(args)
+        }
+    }
+
+    impl<F, C> Fn<(C::Key,)> for MemoizedFix<F, C>
+    where
+        F: Fn(&dyn Fn(C::Key) -> C::Value, C::Key) -> C::Value,
+        C: Cache<Key: Clone, Value: Clone>,
+    {
+        #[inline]
+        extern "rust-call" fn call(&self, (key,): (C::Key,)) -> Self::Output {
+            if let Some(value) = self.cache.get(&key) {
+                self.counters.hit();
+                return value;
+            }
+            self.counters.miss();
+
+            // the cache must not be borrowed here, as the body may recurse back into this function
+            let value = (self.body)(self, key.clone());
+            self.cache.insert(key, value.clone());
+            value
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::memo::{CacheStats, LruCache, SyncCache, memoize, memoize_fix, memoize_with};
+
+    #[test]
+    fn counts_hits_and_misses() {
+        let square = memoize(|n: u64| n.saturating_mul(n));
+        assert_eq!([3, 4, 3, 3].map(&square), [9, 16, 9, 9]);
+        assert_eq!(square.stats(), CacheStats { hits: 2, misses: 2 });
+        assert_eq!(square.cache().len(), 2);
+    }
+
+    #[test]
+    fn memoizes_recursive_calls() {
+        let fib = memoize_fix(|fib, n: u64| -> u64 {
+            if n < 2 {
+                n
+            } else {
+                fib(n.saturating_sub(1)).saturating_add(fib(n.saturating_sub(2)))
+            }
+        });
+        assert_eq!(fib(80), 23_416_728_348_467_685);
+        assert_eq!(
+            fib.stats(),
+            CacheStats {
+                hits: 78,
+                misses: 81
+            }
+        );
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let double = memoize_with(|n: u32| n.saturating_mul(2), LruCache::new(2));
+        double(1);
+        double(2);
+        double(1);
+        double(3); // evicts 2, as 1 was used more recently
+        double(1);
+        double(2);
+        assert_eq!(double.stats(), CacheStats { hits: 2, misses: 4 });
+        assert_eq!(double.cache().len(), 2);
+    }
+
+    #[test]
+    fn shares_between_threads() {
+        let len = memoize_with(|s: &'static str| s.len(), SyncCache::new());
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| len("four"));
+            }
+        });
+        assert_eq!(len.stats().hits.saturating_add(len.stats().misses), 4);
+        assert_eq!(len.cache().len(), 1);
+    }
+}
@@ -0,0 +1,178 @@
+//! Proc-macros for the family-pattern encoding of HKTs in `hkts`, which generate the boilerplate
+//! of type-constructor marker-types and their trait implementations
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{DeriveInput, GenericParam, Ident, TypeParam, parse_macro_input};
+
+type TokS2 = proc_macro2::TokenStream;
+
+/// The maximum number of generic positions that there are `TyConstructor*` traits for
+const MAX_ARITY: usize = 8;
+
+/// Derives the type-constructor marker-types of a generic data-type, and the `TyConstructor*` and
+/// `ConstructableTy*` implementations linking them to the data-type.
+///
+/// The constructed generic positions are the type parameters marked with `#[hkt]`, or all of them
+/// if none are marked; all the other type parameters are held fixed by every constructor. For a
+/// data-type `Foo<A, B>` this generates:
+///  - `FooConstructor`, implementing `TyConstructor2` with `TC2<A, B> = Foo<A, B>`;
+///  - `FooAConstructor<A>`, implementing `TyConstructor1` with `TC1<B> = Foo<A, B>`, i.e. the
+///    partial application of `FooConstructor` to its first generic position;
+///  - and the matching `ConstructableTy2` and `ConstructableTy1` implementations for `Foo<A, B>`.
+///
+/// The constructed positions cannot have bounds, as the `TC*` associated types are unbounded.
+#[allow(clippy::missing_inline_in_public_items)]
+#[proc_macro_derive(HktConstructor, attributes(hkt))]
+pub fn derive_hkt_constructor(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    match hkt_constructor(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn hkt_constructor(input: &DeriveInput) -> syn::Result<TokS2> {
+    if let Some(ref where_clause) = input.generics.where_clause {
+        return Err(syn::Error::new_spanned(
+            where_clause,
+            "`HktConstructor` does not support where-clauses, use inline bounds instead",
+        ));
+    }
+
+    // split the type parameters into the constructed positions and the fixed ones
+    let mut params = Vec::new();
+    for param in &input.generics.params {
+        match *param {
+            GenericParam::Type(ref param) => params.push(param),
+            GenericParam::Lifetime(_) | GenericParam::Const(_) => {
+                return Err(syn::Error::new_spanned(
+                    param,
+                    "`HktConstructor` only supports type parameters",
+                ));
+            }
+        }
+    }
+    let any_marked = params.iter().any(|param| is_marked(param));
+    let (positions, fixed): (Vec<&TypeParam>, Vec<&TypeParam>) = params
+        .iter()
+        .partition(|param| !any_marked || is_marked(param));
+    if positions.is_empty() || positions.len() > MAX_ARITY {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            format!("`HktConstructor` needs between 1 and {MAX_ARITY} constructed type parameters"),
+        ));
+    }
+    if let Some(bounded) = positions.iter().find(|param| !param.bounds.is_empty()) {
+        return Err(syn::Error::new_spanned(
+            bounded,
+            "constructed type parameters cannot have bounds",
+        ));
+    }
+
+    // every constructor applies the fixed parameters and a (possibly empty) prefix of positions
+    let mut tokens = TokS2::new();
+    for applied in 0..positions.len() {
+        tokens.extend(constructor(input, &params, &fixed, &positions, applied));
+    }
+    Ok(tokens)
+}
+
+/// Whether the type parameter is marked as a constructed position with `#[hkt]`
+fn is_marked(param: &TypeParam) -> bool {
+    param.attrs.iter().any(|attr| attr.path().is_ident("hkt"))
+}
+
+/// Generates the constructor which has the first `applied` positions already applied
+fn constructor(
+    input: &DeriveInput,
+    params: &[&TypeParam],
+    fixed: &[&TypeParam],
+    positions: &[&TypeParam],
+    applied: usize,
+) -> TokS2 {
+    let (vis, ty) = (&input.vis, &input.ident);
+    let (applied_positions, open_positions) = positions.split_at(applied);
+    let arity = open_positions.len();
+
+    // the constructor holds on to every parameter that is not an open position
+    let held = fixed.iter().chain(applied_positions).copied();
+    let held_names = held.clone().map(|param| &param.ident).collect::<Vec<_>>();
+    let held_bounded = held
+        .map(|param| {
+            let (name, bounds) = (&param.ident, &param.bounds);
+            if bounds.is_empty() {
+                quote! { #name }
+            } else {
+                quote! { #name: #bounds }
+            }
+        })
+        .collect::<Vec<_>>();
+    let open_names = open_positions
+        .iter()
+        .map(|param| &param.ident)
+        .collect::<Vec<_>>();
+    let all_names = params.iter().map(|param| &param.ident).collect::<Vec<_>>();
+
+    let applied_suffix = applied_positions
+        .iter()
+        .map(|param| param.ident.to_string())
+        .collect::<String>();
+    let constructor = format_ident!("{ty}{applied_suffix}Constructor");
+    let tc = format_ident!("TC{arity}");
+    let ty_constructor = format_ident!("TyConstructor{arity}");
+    let constructable_ty = format_ident!("ConstructableTy{arity}");
+    let generic_parameters =
+        (1..=arity).map(|i| Ident::new(&format!("GenericParameter{i}"), Span::call_site()));
+
+    // e.g. `Result<T,_>` for the constructor which has `T` applied
+    let shape = params
+        .iter()
+        .map(|param| {
+            if open_names.contains(&&param.ident) {
+                "_".to_owned()
+            } else {
+                param.ident.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    let doc = format!(" The type-constructor for the `{ty}<{shape}>` data-type.");
+
+    let marker = if held_names.is_empty() {
+        quote! {
+            #[doc = #doc]
+            #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+            #vis struct #constructor;
+        }
+    } else {
+        quote! {
+            #[doc = #doc]
+            #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+            #[repr(transparent)]
+            #vis struct #constructor<#(#held_bounded),*>(
+                ::core::marker::PhantomData<(#(#held_names,)*)>
+            );
+        }
+    };
+
+    quote! {
+        #marker
+
+        impl<#(#held_bounded,)* #(#open_names),*> ::hkts::family_pattern::#constructable_ty
+            for #ty<#(#all_names),*>
+        {
+            #(type #generic_parameters = #open_names;)*
+            type Constructor = #constructor<#(#held_names),*>;
+        }
+
+        impl<#(#held_bounded),*> ::hkts::family_pattern::#ty_constructor
+            for #constructor<#(#held_names),*>
+        {
+            type #tc<#(#open_names),*> = #ty<#(#all_names),*>;
+        }
+    }
+}
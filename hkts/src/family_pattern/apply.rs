@@ -0,0 +1,176 @@
+//! Partial application of type-constructors, i.e. fixing one of the generic positions of a
+//! [`TyConstructor2`] to obtain a [`TyConstructor1`] over the other one, and likewise for
+//! [`TyConstructor3`].
+//!
+//! The constructed types are newtype-wrappers around the types constructed by the original
+//! type-constructor, because the foreign types those are cannot be given a second
+//! [`ConstructableTy1`] implementation pointing back at these constructors. With these, any
+//! multi-parameter type can be given e.g. `Functor`/`Monad` instances in its free position, without
+//! a bespoke marker-type like `ResultTConstructor`.
+
+use crate::family_pattern::{TyConstructor2, TyConstructor3};
+use std::marker::PhantomData;
+
+/// The type-constructor for `T<A,_>`, i.e. [`TyConstructor2`] `T` with its first position fixed
+/// to `A`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Apply1st<T: TyConstructor2, A>(PhantomData<(T, A)>);
+
+/// The type-constructor for `T<_,B>`, i.e. [`TyConstructor2`] `T` with its second position fixed
+/// to `B`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Apply2nd<T: TyConstructor2, B>(PhantomData<(T, B)>);
+
+/// The type constructed by [`Apply1st`], wrapping `T<A,B>`.
+#[repr(transparent)]
+pub struct Applied1st<T: TyConstructor2, A, B>(pub T::TC2<A, B>);
+
+/// The type constructed by [`Apply2nd`], wrapping `T<A,B>`.
+#[repr(transparent)]
+pub struct Applied2nd<T: TyConstructor2, A, B>(pub T::TC2<A, B>);
+
+/// The type-constructor for `T<A,_,_>`, i.e. [`TyConstructor3`] `T` with its first position
+/// fixed to `A`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Apply1stOf3<T: TyConstructor3, A>(PhantomData<(T, A)>);
+
+/// The type-constructor for `T<_,B,_>`, i.e. [`TyConstructor3`] `T` with its second position
+/// fixed to `B`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Apply2ndOf3<T: TyConstructor3, B>(PhantomData<(T, B)>);
+
+/// The type-constructor for `T<_,_,C>`, i.e. [`TyConstructor3`] `T` with its third position
+/// fixed to `C`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Apply3rdOf3<T: TyConstructor3, C>(PhantomData<(T, C)>);
+
+/// The type constructed by [`Apply1stOf3`], wrapping `T<A,B,C>`.
+#[repr(transparent)]
+pub struct Applied1stOf3<T: TyConstructor3, A, B, C>(pub T::TC3<A, B, C>);
+
+/// The type constructed by [`Apply2ndOf3`], wrapping `T<A,B,C>`.
+#[repr(transparent)]
+pub struct Applied2ndOf3<T: TyConstructor3, A, B, C>(pub T::TC3<A, B, C>);
+
+/// The type constructed by [`Apply3rdOf3`], wrapping `T<A,B,C>`.
+#[repr(transparent)]
+pub struct Applied3rdOf3<T: TyConstructor3, A, B, C>(pub T::TC3<A, B, C>);
+
+mod apply2_impls {
+    use crate::family_pattern::apply::{Applied1st, Applied2nd, Apply1st, Apply2nd};
+    use crate::family_pattern::{ConstructableTy1, TyConstructor1, TyConstructor2};
+
+    impl<T: TyConstructor2, A, B> ConstructableTy1 for Applied1st<T, A, B> {
+        type GenericParameter1 = B;
+        type Constructor = Apply1st<T, A>;
+    }
+
+    impl<T: TyConstructor2, A> TyConstructor1 for Apply1st<T, A> {
+        type TC1<B> = Applied1st<T, A, B>;
+    }
+
+    impl<T: TyConstructor2, A, B> ConstructableTy1 for Applied2nd<T, A, B> {
+        type GenericParameter1 = A;
+        type Constructor = Apply2nd<T, B>;
+    }
+
+    impl<T: TyConstructor2, B> TyConstructor1 for Apply2nd<T, B> {
+        type TC1<A> = Applied2nd<T, A, B>;
+    }
+}
+
+mod apply3_impls {
+    use crate::family_pattern::apply::{
+        Applied1stOf3, Applied2ndOf3, Applied3rdOf3, Apply1stOf3, Apply2ndOf3, Apply3rdOf3,
+    };
+    use crate::family_pattern::{ConstructableTy2, TyConstructor2, TyConstructor3};
+
+    impl<T: TyConstructor3, A, B, C> ConstructableTy2 for Applied1stOf3<T, A, B, C> {
+        type GenericParameter1 = B;
+        type GenericParameter2 = C;
+        type Constructor = Apply1stOf3<T, A>;
+    }
+
+    impl<T: TyConstructor3, A> TyConstructor2 for Apply1stOf3<T, A> {
+        type TC2<B, C> = Applied1stOf3<T, A, B, C>;
+    }
+
+    impl<T: TyConstructor3, A, B, C> ConstructableTy2 for Applied2ndOf3<T, A, B, C> {
+        type GenericParameter1 = A;
+        type GenericParameter2 = C;
+        type Constructor = Apply2ndOf3<T, B>;
+    }
+
+    impl<T: TyConstructor3, B> TyConstructor2 for Apply2ndOf3<T, B> {
+        type TC2<A, C> = Applied2ndOf3<T, A, B, C>;
+    }
+
+    impl<T: TyConstructor3, A, B, C> ConstructableTy2 for Applied3rdOf3<T, A, B, C> {
+        type GenericParameter1 = A;
+        type GenericParameter2 = B;
+        type Constructor = Apply3rdOf3<T, C>;
+    }
+
+    impl<T: TyConstructor3, C> TyConstructor2 for Apply3rdOf3<T, C> {
+        type TC2<A, B> = Applied3rdOf3<T, A, B, C>;
+    }
+}
+
+// the wrappers are compared, cloned and printed as the types they wrap, rather than bounding the
+// type-constructors themselves, as derived implementations would
+macro_rules! impl_std_for_applied {
+    ($Applied:ident<$T:ident: $Tc:ident $(, $P:ident)+>, $Inner:ty) => {
+        impl<$T: $Tc $(, $P)+> Clone for $Applied<$T $(, $P)+>
+        where
+            $Inner: Clone,
+        {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self(self.0.clone())
+            }
+        }
+
+        impl<$T: $Tc $(, $P)+> Copy for $Applied<$T $(, $P)+> where $Inner: Copy {}
+
+        impl<$T: $Tc $(, $P)+> PartialEq for $Applied<$T $(, $P)+>
+        where
+            $Inner: PartialEq,
+        {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<$T: $Tc $(, $P)+> Eq for $Applied<$T $(, $P)+> where $Inner: Eq {}
+
+        impl<$T: $Tc $(, $P)+> Debug for $Applied<$T $(, $P)+>
+        where
+            $Inner: Debug,
+        {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($Applied)).field(&self.0).finish()
+            }
+        }
+    };
+}
+
+mod std_impls {
+    use crate::family_pattern::apply::{
+        Applied1st, Applied1stOf3, Applied2nd, Applied2ndOf3, Applied3rdOf3,
+    };
+    use crate::family_pattern::{TyConstructor2, TyConstructor3};
+    use std::fmt::{self, Debug, Formatter};
+
+    impl_std_for_applied!(Applied1st<T: TyConstructor2, A, B>, T::TC2<A, B>);
+    impl_std_for_applied!(Applied2nd<T: TyConstructor2, A, B>, T::TC2<A, B>);
+    impl_std_for_applied!(Applied1stOf3<T: TyConstructor3, A, B, C>, T::TC3<A, B, C>);
+    impl_std_for_applied!(Applied2ndOf3<T: TyConstructor3, A, B, C>, T::TC3<A, B, C>);
+    impl_std_for_applied!(Applied3rdOf3<T: TyConstructor3, A, B, C>, T::TC3<A, B, C>);
+}
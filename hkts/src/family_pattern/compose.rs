@@ -0,0 +1,146 @@
+//! Composition of type-constructors, i.e. `F<G<_>>` as a [`TyConstructor1`] in its own right, so
+//! that e.g. `Vec<Option<A>>` can be mapped over, folded and traversed without nesting the calls.
+//!
+//! Like [`apply`](crate::family_pattern::apply), the constructed types are newtype-wrappers around
+//! `F<G<A>>`, because that already has a [`ConstructableTy1`](crate::family_pattern::ConstructableTy1)
+//! implementation pointing back at `F`.
+
+use crate::family_pattern::TyConstructor1;
+use std::marker::PhantomData;
+
+/// The type-constructor for `F<G<_>>`, i.e. the composition of [`TyConstructor1`]s `F` and `G`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Compose<F: TyConstructor1, G: TyConstructor1>(PhantomData<(F, G)>);
+
+/// The type constructed by [`Compose`], wrapping `F<G<A>>`.
+///
+/// Its `Clone`/`Copy`/`PartialEq`/`Eq`/`Debug` implementations are those of `F<G<A>>`, rather
+/// than derived ones, which would bound the type-constructors `F` and `G` instead.
+#[repr(transparent)]
+pub struct Composed<F: TyConstructor1, G: TyConstructor1, A>(pub F::TC1<G::TC1<A>>);
+
+mod ty1_impls {
+    use crate::family_pattern::compose::{Compose, Composed};
+    use crate::family_pattern::{ConstructableTy1, TyConstructor1};
+
+    impl<F: TyConstructor1, G: TyConstructor1, A> ConstructableTy1 for Composed<F, G, A> {
+        type GenericParameter1 = A;
+        type Constructor = Compose<F, G>;
+    }
+
+    impl<F: TyConstructor1, G: TyConstructor1> TyConstructor1 for Compose<F, G> {
+        type TC1<A> = Composed<F, G, A>;
+    }
+}
+
+mod std_impls {
+    use crate::family_pattern::TyConstructor1;
+    use crate::family_pattern::compose::Composed;
+    use std::fmt::{self, Debug, Formatter};
+
+    impl<F: TyConstructor1, G: TyConstructor1, A> Clone for Composed<F, G, A>
+    where
+        F::TC1<G::TC1<A>>: Clone,
+    {
+        #[inline]
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+
+    impl<F: TyConstructor1, G: TyConstructor1, A> Copy for Composed<F, G, A> where
+        F::TC1<G::TC1<A>>: Copy
+    {
+    }
+
+    impl<F: TyConstructor1, G: TyConstructor1, A> PartialEq for Composed<F, G, A>
+    where
+        F::TC1<G::TC1<A>>: PartialEq,
+    {
+        #[inline]
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl<F: TyConstructor1, G: TyConstructor1, A> Eq for Composed<F, G, A> where F::TC1<G::TC1<A>>: Eq {}
+
+    impl<F: TyConstructor1, G: TyConstructor1, A> Debug for Composed<F, G, A>
+    where
+        F::TC1<G::TC1<A>>: Debug,
+    {
+        #[inline]
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("Composed").field(&self.0).finish()
+        }
+    }
+}
+
+// the instances of `F<G<_>>` apply those of `G` within those of `F`; they are given at the `Mut`
+// level, which every `Once` instance implies through the blanket hierarchy, so that e.g.
+// `Vec<Option<_>>` has them as well as `Option<Option<_>>`
+mod typeclass_impls {
+    use crate::family_pattern::compose::{Compose, Composed};
+    use crate::family_pattern::typeclasses::ap::ApMut;
+    use crate::family_pattern::typeclasses::applicative::ApplicativeMut;
+    use crate::family_pattern::typeclasses::foldable::FoldableMut;
+    use crate::family_pattern::typeclasses::functor::FunctorMut;
+    use crate::family_pattern::typeclasses::pure::Pure;
+    use crate::family_pattern::typeclasses::traverse::TraverseMut;
+
+    impl<F: FunctorMut, G: FunctorMut> FunctorMut for Compose<F, G> {
+        #[inline]
+        fn fmap_mut<A, B, _F: FnMut(A) -> B>(
+            fa: Composed<F, G, A>,
+            mut f: _F,
+        ) -> Composed<F, G, B> {
+            Composed(F::fmap_mut(fa.0, |ga| G::fmap_mut(ga, &mut f)))
+        }
+    }
+
+    impl<F: Pure, G: Pure> Pure for Compose<F, G> {
+        #[inline]
+        fn pure<A>(a: A) -> Composed<F, G, A> {
+            Composed(F::pure(G::pure(a)))
+        }
+    }
+
+    impl<F: ApMut, G: ApMut> ApMut for Compose<F, G> {
+        #[inline]
+        fn lift_2a_mut<A, B, C, _F: FnMut(A, B) -> C>(
+            fa: Composed<F, G, A>,
+            fb: Composed<F, G, B>,
+            mut f: _F,
+        ) -> Composed<F, G, C> {
+            Composed(F::lift_2a_mut(fa.0, fb.0, |ga, gb| {
+                G::lift_2a_mut(ga, gb, &mut f)
+            }))
+        }
+    }
+
+    impl<F: FoldableMut, G: FoldableMut> FoldableMut for Compose<F, G> {
+        #[inline]
+        fn foldr_mut<A, S, _F: FnMut(A, S) -> S>(ta: Composed<F, G, A>, s: S, mut f: _F) -> S {
+            F::foldr_mut(ta.0, s, |ga, s| G::foldr_mut(ga, s, &mut f))
+        }
+
+        #[inline]
+        fn foldl_mut<A, S, _F: FnMut(S, A) -> S>(ta: Composed<F, G, A>, s: S, mut f: _F) -> S {
+            F::foldl_mut(ta.0, s, |s, ga| G::foldl_mut(ga, s, &mut f))
+        }
+    }
+
+    impl<F: TraverseMut, G: TraverseMut> TraverseMut for Compose<F, G> {
+        #[inline]
+        fn traverse_mut<H: ApplicativeMut, A, B, _F: FnMut(A) -> H::TC1<B>>(
+            ta: Composed<F, G, A>,
+            mut f: _F,
+        ) -> H::TC1<Composed<F, G, B>> {
+            let tb = F::traverse_mut::<H, G::TC1<A>, G::TC1<B>, _>(ta.0, |ga| {
+                G::traverse_mut::<H, A, B, _>(ga, &mut f)
+            });
+            H::fmap_mut(tb, Composed)
+        }
+    }
+}
@@ -0,0 +1,149 @@
+/// The type-constructor for the `&'a _` data-type.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+pub struct RefConstructor;
+
+/// The type-constructor for the `Cow<'a, _>` data-type.
+///
+/// It is a [`ToOwnedTyConstructorL1`](crate::family_pattern::ToOwnedTyConstructorL1) rather than
+/// a [`TyConstructorL1`](crate::family_pattern::TyConstructorL1), and has no borrowing typeclass
+/// instances, because its owned variant cannot lend `&'a A`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+pub struct CowConstructor;
+
+/// The type-constructor for the `&'a [_]` data-type.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+pub struct SliceConstructor;
+
+/// The type-constructor for the `std::slice::Iter<'a, _>` data-type.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+pub struct SliceIterConstructor;
+
+mod ref_impls {
+    use crate::family_pattern::data::borrowed::RefConstructor;
+    use crate::family_pattern::data::option::OptionConstructor;
+    use crate::family_pattern::typeclasses::foldable::FoldableL1;
+    use crate::family_pattern::typeclasses::functor::FunctorL1;
+    use crate::family_pattern::{ConstructableTyL1, TyConstructorL1};
+
+    impl<'a, A: 'a> ConstructableTyL1<'a> for &'a A {
+        type GenericParameter1 = A;
+        type Constructor = RefConstructor;
+    }
+
+    impl<'a> TyConstructorL1<'a> for RefConstructor {
+        type TC1<A: 'a> = &'a A;
+    }
+
+    // there is no identity type-constructor, so the single borrowed value maps into `Some(_)`
+    impl<'a> FunctorL1<'a> for RefConstructor {
+        type Owned = OptionConstructor;
+
+        #[inline]
+        fn fmap_ref<A: 'a, B, F: FnMut(&'a A) -> B>(fa: &'a A, mut f: F) -> Option<B> {
+            Some(f(fa))
+        }
+    }
+
+    impl<'a> FoldableL1<'a> for RefConstructor {
+        #[inline]
+        fn foldr_ref<A: 'a, S, F: FnMut(&'a A, S) -> S>(ta: &'a A, s: S, mut f: F) -> S {
+            f(ta, s)
+        }
+
+        #[inline]
+        fn foldl_ref<A: 'a, S, F: FnMut(S, &'a A) -> S>(ta: &'a A, s: S, mut f: F) -> S {
+            f(s, ta)
+        }
+    }
+}
+
+mod cow_impls {
+    use crate::family_pattern::ToOwnedTyConstructorL1;
+    use crate::family_pattern::data::borrowed::CowConstructor;
+    use std::borrow::Cow;
+
+    impl<'a> ToOwnedTyConstructorL1<'a> for CowConstructor {
+        type TC1<A: 'a + ToOwned + ?Sized> = Cow<'a, A>;
+    }
+}
+
+mod slice_impls {
+    use crate::family_pattern::data::borrowed::SliceConstructor;
+    use crate::family_pattern::data::vec::VecConstructor;
+    use crate::family_pattern::typeclasses::foldable::FoldableL1;
+    use crate::family_pattern::typeclasses::functor::FunctorL1;
+    use crate::family_pattern::{ConstructableTyL1, TyConstructorL1};
+
+    impl<'a, A: 'a> ConstructableTyL1<'a> for &'a [A] {
+        type GenericParameter1 = A;
+        type Constructor = SliceConstructor;
+    }
+
+    impl<'a> TyConstructorL1<'a> for SliceConstructor {
+        type TC1<A: 'a> = &'a [A];
+    }
+
+    impl<'a> FunctorL1<'a> for SliceConstructor {
+        type Owned = VecConstructor;
+
+        #[inline]
+        fn fmap_ref<A: 'a, B, F: FnMut(&'a A) -> B>(fa: &'a [A], f: F) -> Vec<B> {
+            fa.iter().map(f).collect()
+        }
+    }
+
+    impl<'a> FoldableL1<'a> for SliceConstructor {
+        #[inline]
+        fn foldr_ref<A: 'a, S, F: FnMut(&'a A, S) -> S>(ta: &'a [A], s: S, mut f: F) -> S {
+            ta.iter().rev().fold(s, |s, a| f(a, s))
+        }
+
+        #[inline]
+        fn foldl_ref<A: 'a, S, F: FnMut(S, &'a A) -> S>(ta: &'a [A], s: S, f: F) -> S {
+            ta.iter().fold(s, f)
+        }
+    }
+}
+
+mod slice_iter_impls {
+    use crate::family_pattern::data::borrowed::SliceIterConstructor;
+    use crate::family_pattern::data::vec::VecConstructor;
+    use crate::family_pattern::typeclasses::foldable::FoldableL1;
+    use crate::family_pattern::typeclasses::functor::FunctorL1;
+    use crate::family_pattern::{ConstructableTyL1, TyConstructorL1};
+    use std::slice;
+
+    impl<'a, A: 'a> ConstructableTyL1<'a> for slice::Iter<'a, A> {
+        type GenericParameter1 = A;
+        type Constructor = SliceIterConstructor;
+    }
+
+    impl<'a> TyConstructorL1<'a> for SliceIterConstructor {
+        type TC1<A: 'a> = slice::Iter<'a, A>;
+    }
+
+    impl<'a> FunctorL1<'a> for SliceIterConstructor {
+        type Owned = VecConstructor;
+
+        #[inline]
+        fn fmap_ref<A: 'a, B, F: FnMut(&'a A) -> B>(fa: slice::Iter<'a, A>, f: F) -> Vec<B> {
+            fa.map(f).collect()
+        }
+    }
+
+    impl<'a> FoldableL1<'a> for SliceIterConstructor {
+        #[inline]
+        fn foldr_ref<A: 'a, S, F: FnMut(&'a A, S) -> S>(
+            ta: slice::Iter<'a, A>,
+            s: S,
+            mut f: F,
+        ) -> S {
+            ta.rev().fold(s, |s, a| f(a, s))
+        }
+
+        #[inline]
+        fn foldl_ref<A: 'a, S, F: FnMut(S, &'a A) -> S>(ta: slice::Iter<'a, A>, s: S, f: F) -> S {
+            ta.fold(s, f)
+        }
+    }
+}
@@ -1,16 +1,38 @@
+pub mod borrowed;
 pub mod option;
 pub mod result;
-mod vec;
+pub mod tuple;
+pub mod vec;
 
 #[cfg(test)]
 mod tests {
+    use crate::family_pattern::apply::{
+        Applied1st, Applied2nd, Applied2ndOf3, Apply1st, Apply2nd, Apply2ndOf3,
+    };
+    use crate::family_pattern::compose::{Compose, Composed};
+    use crate::family_pattern::data::borrowed::{CowConstructor, RefConstructor, SliceConstructor};
+    use crate::family_pattern::data::option::OptionConstructor;
+    use crate::family_pattern::data::result::ResultConstructor;
+    use crate::family_pattern::data::tuple::TupleConstructor;
+    use crate::family_pattern::data::vec::{CloneVec, CloneVecConstructor, VecConstructor};
+    use crate::family_pattern::flip::{Flip, FlipSyntax as _, Flipped};
     use crate::family_pattern::syntax::*;
+    use crate::family_pattern::typeclasses::monad::Monad;
+    use crate::family_pattern::{
+        ConstructableTyExt1 as _, HktConstructor, TC1, TC2, TC3, TC5, TCL1, TCOL1,
+    };
+    use std::borrow::Cow;
     use std::ops::Add;
+    use std::sync::atomic::{AtomicU8, Ordering};
 
     fn double<T: Add<T, Output = T> + Clone>(x: T) -> T {
         x.clone() + x
     }
 
+    fn sum_then_pure<M: Monad>(ma: M::TC1<u32>, mb: M::TC1<u32>) -> M::TC1<u32> {
+        M::bind(M::lift_2a(ma, mb, u32::saturating_add), M::pure)
+    }
+
     fn checked_double(x: u32) -> Option<u32> {
         x.checked_add(x)
     }
@@ -42,4 +64,215 @@ mod tests {
 
         // test traverse
     }
+
+    #[derive(HktConstructor, PartialEq, Debug)]
+    struct Triple<A, B, C>(A, B, C);
+
+    #[derive(HktConstructor, PartialEq, Debug)]
+    struct Record<A, B, C, D, E>(A, B, C, D, E);
+
+    #[derive(HktConstructor, PartialEq, Debug)]
+    struct Tagged<Tag: Copy, #[hkt] A>(Tag, A);
+
+    #[test]
+    pub fn derived_constructors() {
+        // the full constructor, and its partial applications to the leading positions
+        let full: TC3<TripleConstructor, u8, u16, u32> = Triple(1, 2, 3);
+        let applied_a: TC2<TripleAConstructor<u8>, u16, u32> = full;
+        let applied_ab: TC1<TripleABConstructor<u8, u16>, u32> = applied_a;
+        assert_eq!(applied_ab.reify1(), Triple(1, 2, 3));
+
+        // constructors beyond the third arity
+        let record: TC5<RecordConstructor, u8, u8, u8, u8, &str> = Record(1, 2, 3, 4, "five");
+        assert_eq!(record.reify1(), Record(1, 2, 3, 4, "five"));
+
+        // fixed parameters are held by every constructor
+        let tagged: TC1<TaggedConstructor<&str>, u8> = Tagged("tag", 1);
+        assert_eq!(tagged.reify1(), Tagged("tag", 1));
+    }
+
+    #[test]
+    pub fn applied_constructors() {
+        type ResultStr = Apply2nd<ResultConstructor, &'static str>;
+
+        // `Result<_,E>` is a monad in its free position, without a bespoke constructor
+        let parsed: Applied2nd<ResultConstructor, u32, &str> = Applied2nd(Ok(21));
+        let doubled = parsed
+            .fmap(|n| n.saturating_mul(2))
+            .bind(|n| Applied2nd(if n > 40 { Ok(n) } else { Err("too small") }));
+        assert_eq!(doubled, Applied2nd(Ok(42)));
+
+        let summed = sum_then_pure::<ResultStr>(Applied2nd(Ok(20)), Applied2nd(Ok(22)));
+        assert_eq!(summed, Applied2nd(Ok(42)));
+        let failed =
+            sum_then_pure::<ResultStr>(Applied2nd(Err("first")), Applied2nd(Err("second")));
+        assert_eq!(failed, Applied2nd(Err("first")));
+
+        // the other positions of derived constructors can be fixed just the same
+        let applied: TC2<Apply2ndOf3<TripleConstructor, u8>, u16, u32> =
+            Applied2ndOf3(Triple(1, 2, 3));
+        assert_eq!(applied, Applied2ndOf3(Triple(1, 2, 3)));
+    }
+
+    #[test]
+    pub fn flipped_constructors() {
+        // flipping is a round-trip
+        let flipped: Flipped<ResultConstructor, &str, u32> = Ok::<u32, &str>(7).flip();
+        assert_eq!(flipped.unflip(), Ok(7));
+        assert_eq!((1_u8, "one").flip().unflip(), (1, "one"));
+
+        // `Apply1st<Flip<ResultConstructor>, E>` is `Result<_,E>`
+        let parsed: TC1<Apply1st<Flip<ResultConstructor>, &str>, u32> =
+            Applied1st(Ok::<u32, &str>(21).flip());
+        let doubled = parsed
+            .fmap(|n| n.saturating_mul(2))
+            .bind(|n| Applied1st(Flipped(if n > 40 { Ok(n) } else { Err("too small") })));
+        assert_eq!(doubled, Applied1st(Flipped(Ok(42))));
+
+        let failed: TC1<Apply1st<Flip<ResultConstructor>, &str>, u32> =
+            Applied1st(Err::<u32, &str>("nope").flip());
+        assert_eq!(failed.foldr(0, |n, s| n + s), 0);
+
+        // `Apply2nd<Flip<TupleConstructor>, A>` maps over the second element, like `(A,_)`
+        let pair: TC1<Apply2nd<Flip<TupleConstructor>, &str>, u8> =
+            Applied2nd(("label", 20_u8).flip());
+        assert_eq!(
+            pair.fmap(|n| n.saturating_add(1)),
+            Applied2nd(Flipped(("label", 21)))
+        );
+        assert_eq!(format!("{pair:?}"), r#"Applied2nd(Flipped(("label", 20)))"#);
+
+        let labelled: TC1<Apply2nd<Flip<TupleConstructor>, &str>, u8> =
+            Applied2nd(("label", 20_u8).flip());
+        assert_eq!(labelled.foldl(1, |s, n| s + n), 21);
+    }
+
+    #[test]
+    pub fn constrained_constructors() {
+        // `CloneVec` is a monad under the constrained typeclasses, as they supply its `CloneWit`s
+        let xs: CloneVec<u32> = vec![1, 2].into();
+        let ys = 10_u32.cpure::<CloneVec<_>>();
+        let summed = (xs.cfmap(double), ys).clift_2a(Add::add);
+        assert_eq!(summed.0, vec![12, 14]);
+
+        let double_u32: fn(u32) -> u32 = double;
+        let doublings = CloneVec::from(vec![double_u32]);
+        let bound = doublings
+            .cap(summed)
+            .cbind(|n| CloneVec::from(vec![n, n.saturating_add(1)]));
+        assert_eq!(bound.0, vec![24, 25, 28, 29]);
+        assert_eq!(bound.clone().0, bound.0);
+
+        // and unconstrained type-constructors are lifted into them
+        assert_eq!(Some(21_u32).cfmap(double).cbind(checked_double), Some(84));
+        let _: TC1<CloneVecConstructor, ()> = ().cpure::<CloneVec<_>>();
+    }
+
+    #[test]
+    pub fn borrowed_constructors() {
+        // borrowed views are mapped into owned values, and folded over, without cloning them
+        let names = vec![String::from("ab"), String::from("cde")];
+        let view: TCL1<'_, SliceConstructor, String> = &names;
+        assert_eq!(view.fmap_ref(String::len), vec![2, 3]);
+        assert_eq!(
+            view.foldl_ref(0_usize, |len, n| len.saturating_add(n.len())),
+            5
+        );
+        assert_eq!(
+            names
+                .iter()
+                .foldr_ref(vec![], |n, ns| [ns, vec![n.as_str()]].concat()),
+            ["cde", "ab"]
+        );
+        assert_eq!(names.iter().fmap_ref(String::as_str), vec!["ab", "cde"]);
+
+        let label = String::from("label");
+        let borrowed: TCL1<'_, RefConstructor, String> = &label;
+        assert_eq!(
+            borrowed.foldr_ref(0_usize, |n, len| n.len().saturating_add(len)),
+            5
+        );
+        assert_eq!(borrowed.fmap_ref(String::as_str), Some("label"));
+
+        // clone-on-write values are constructed too, whether they borrow or own their value
+        let cow_borrowed: TCOL1<'_, CowConstructor, str> = Cow::Borrowed(label.as_str());
+        assert!(matches!(cow_borrowed, Cow::Borrowed("label")));
+        let cow_owned: TCOL1<'_, CowConstructor, str> = Cow::Owned(String::from("owned"));
+        assert_eq!(cow_owned.into_owned(), "owned");
+
+        // and views of data that cannot be cloned are constructed all the same
+        let counters = [AtomicU8::new(1), AtomicU8::new(2)];
+        let counted: TCL1<'_, SliceConstructor, AtomicU8> = &counters;
+        assert_eq!(counted.fmap_ref(|n| n.load(Ordering::Relaxed)), vec![1, 2]);
+    }
+
+    #[test]
+    pub fn composed_constructors() {
+        type VecOption<A> = Composed<VecConstructor, OptionConstructor, A>;
+        type OptionOption<A> = Composed<OptionConstructor, OptionConstructor, A>;
+        type OptionOptionConstructor = Compose<OptionConstructor, OptionConstructor>;
+
+        // `Vec<Option<_>>` is mapped over, folded and traversed as one functor
+        let doubled: VecOption<u32> = Composed(vec![Some(1), None, Some(3)]).fmap(double);
+        assert_eq!(doubled.0, vec![Some(2), None, Some(6)]);
+
+        let summed = Composed::<VecConstructor, OptionConstructor, _>(doubled.0)
+            .foldl(0, u32::saturating_add);
+        assert_eq!(summed, 8);
+
+        let overflowing: VecOption<u32> = Composed(vec![Some(1), None, Some(u32::MAX)]);
+        assert!(
+            overflowing
+                .traverse::<OptionConstructor, _, _>(checked_double)
+                .is_none()
+        );
+
+        let fitting: VecOption<u32> = Composed(vec![Some(1), None, Some(3)]);
+        let traversed = fitting.traverse::<OptionConstructor, _, _>(checked_double);
+        assert_eq!(
+            traversed.map(|composed| composed.0),
+            Some(vec![Some(2), None, Some(6)])
+        );
+
+        // the layers can be nested either way round
+        let nested: Composed<OptionConstructor, VecConstructor, u32> = Composed(Some(vec![1, 3]));
+        assert_eq!(nested.fmap(double), Composed(Some(vec![2, 6])));
+
+        // and `Option<Option<_>>` is an applicative, as both its layers are
+        let half: OptionOption<u32> = 21_u32.pure::<Composed<_, _, _>>();
+        let other_half: OptionOption<u32> = 21_u32.pure::<Composed<_, _, _>>();
+        let whole = (half, other_half).lift_2a(u32::saturating_add);
+        assert_eq!(whole.0, Some(Some(42)));
+
+        let present: OptionOption<u32> = 21_u32.pure::<Composed<_, _, _>>();
+        let missing: OptionOption<u32> = Composed(Some(None));
+        assert_eq!(
+            (present, missing).lift_2a(u32::saturating_add).0,
+            Some(None)
+        );
+
+        // which makes it the (`Mut`) applicative of other traversals, e.g. of `Vec<Option<_>>`
+        let decrement_double =
+            |n: u32| -> OptionOption<u32> { Composed(n.checked_sub(1).map(checked_double)) };
+        assert_eq!(
+            vec![1, 3].traverse_mut::<OptionOptionConstructor, _, _>(decrement_double),
+            Composed(Some(Some(vec![0, 4])))
+        );
+
+        let decrementable: VecOption<u32> = Composed(vec![Some(1), None, Some(3)]);
+        assert_eq!(
+            decrementable.traverse_mut::<OptionOptionConstructor, _, _>(decrement_double),
+            Composed(Some(Some(Composed(vec![Some(0), None, Some(4)]))))
+        );
+        let underflowing: VecOption<u32> = Composed(vec![Some(1), None, Some(0)]);
+        assert_eq!(
+            underflowing.traverse_mut::<OptionOptionConstructor, _, _>(decrement_double),
+            Composed(None)
+        );
+        let too_large: VecOption<u32> = Composed(vec![Some(1), None, Some(u32::MAX)]);
+        assert_eq!(
+            too_large.traverse_mut::<OptionOptionConstructor, _, _>(decrement_double),
+            Composed(Some(None))
+        );
+    }
 }
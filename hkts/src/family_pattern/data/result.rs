@@ -40,9 +40,82 @@ mod ty1_impls {
         type TC1<E> = Result<T, E>;
     }
 
-    // TODO: we _could_ implement monad and this and that, but thats booooring....
-    //       since mapping over errors is kinds mehhhh,
-    //       what we REALLY want is a type-level "flip" operator to s.t `flip(Result<T, E>) = Result<E, T>`
-    //       that way partially applying `flip(Result<_,_>)` would create type-constructor `Result<_,E>`
-    //       which is what we are REALLY after here....
+    // NOTE: the `Result<_,E>` type-constructor, which is the one worth giving instances to, is
+    //       `Apply2nd<ResultConstructor, E>` or equivalently `Apply1st<Flip<ResultConstructor>, E>`
+}
+
+// the `Result<_,E>` monad, via the generic partial application of `ResultConstructor`
+mod apply2nd_impls {
+    use crate::family_pattern::apply::{Applied2nd, Apply2nd};
+    use crate::family_pattern::data::result::ResultConstructor;
+    use crate::family_pattern::typeclasses::ap::ApOnce;
+    use crate::family_pattern::typeclasses::bind::BindOnce;
+    use crate::family_pattern::typeclasses::foldable::FoldableOnce;
+    use crate::family_pattern::typeclasses::functor::FunctorOnce;
+    use crate::family_pattern::typeclasses::pure::Pure;
+
+    impl<E> FunctorOnce for Apply2nd<ResultConstructor, E> {
+        #[inline]
+        fn fmap_once<A, B, F: FnOnce(A) -> B>(
+            fa: Applied2nd<ResultConstructor, A, E>,
+            f: F,
+        ) -> Applied2nd<ResultConstructor, B, E> {
+            Applied2nd(fa.0.map(f))
+        }
+    }
+
+    impl<E> Pure for Apply2nd<ResultConstructor, E> {
+        #[inline]
+        fn pure<A>(a: A) -> Applied2nd<ResultConstructor, A, E> {
+            Applied2nd(Ok(a))
+        }
+    }
+
+    // the first error wins, like `?` would have it
+    impl<E> ApOnce for Apply2nd<ResultConstructor, E> {
+        #[inline]
+        fn lift_2a_once<A, B, C, F: FnOnce(A, B) -> C>(
+            fa: Applied2nd<ResultConstructor, A, E>,
+            fb: Applied2nd<ResultConstructor, B, E>,
+            f: F,
+        ) -> Applied2nd<ResultConstructor, C, E> {
+            Applied2nd(fa.0.and_then(|a| fb.0.map(|b| f(a, b))))
+        }
+    }
+
+    impl<E> BindOnce for Apply2nd<ResultConstructor, E> {
+        #[inline]
+        fn bind_once<A, B, F: FnOnce(A) -> Applied2nd<ResultConstructor, B, E>>(
+            ta: Applied2nd<ResultConstructor, A, E>,
+            f: F,
+        ) -> Applied2nd<ResultConstructor, B, E> {
+            Applied2nd(ta.0.and_then(|a| f(a).0))
+        }
+    }
+
+    impl<E> FoldableOnce for Apply2nd<ResultConstructor, E> {
+        #[inline]
+        fn foldr_once<A, S, F: FnOnce(A, S) -> S>(
+            ta: Applied2nd<ResultConstructor, A, E>,
+            s: S,
+            f: F,
+        ) -> S {
+            match ta.0 {
+                Ok(a) => f(a, s),
+                Err(_) => s,
+            }
+        }
+
+        #[inline]
+        fn foldl_once<A, S, F: FnOnce(S, A) -> S>(
+            ta: Applied2nd<ResultConstructor, A, E>,
+            s: S,
+            f: F,
+        ) -> S {
+            match ta.0 {
+                Ok(a) => f(s, a),
+                Err(_) => s,
+            }
+        }
+    }
 }
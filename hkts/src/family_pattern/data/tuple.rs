@@ -0,0 +1,58 @@
+/// The type-constructor for the `(_,_)` data-type.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+pub struct TupleConstructor;
+
+mod ty2_impls {
+    use crate::family_pattern::data::tuple::TupleConstructor;
+    use crate::family_pattern::{ConstructableTy2, TyConstructor2};
+
+    impl<A, B> ConstructableTy2 for (A, B) {
+        type GenericParameter1 = A;
+        type GenericParameter2 = B;
+        type Constructor = TupleConstructor;
+    }
+
+    impl TyConstructor2 for TupleConstructor {
+        type TC2<A, B> = (A, B);
+    }
+}
+
+// the `(A,_)` functor, which maps over the second element; the first element is mapped over by
+// flipping the constructor instead, i.e. `Apply2nd<Flip<TupleConstructor>, A>`
+mod apply1st_impls {
+    use crate::family_pattern::apply::{Applied1st, Apply1st};
+    use crate::family_pattern::data::tuple::TupleConstructor;
+    use crate::family_pattern::typeclasses::foldable::FoldableOnce;
+    use crate::family_pattern::typeclasses::functor::FunctorOnce;
+
+    impl<A> FunctorOnce for Apply1st<TupleConstructor, A> {
+        #[inline]
+        fn fmap_once<B, C, F: FnOnce(B) -> C>(
+            fa: Applied1st<TupleConstructor, A, B>,
+            f: F,
+        ) -> Applied1st<TupleConstructor, A, C> {
+            let (a, b) = fa.0;
+            Applied1st((a, f(b)))
+        }
+    }
+
+    impl<A> FoldableOnce for Apply1st<TupleConstructor, A> {
+        #[inline]
+        fn foldr_once<B, S, F: FnOnce(B, S) -> S>(
+            ta: Applied1st<TupleConstructor, A, B>,
+            s: S,
+            f: F,
+        ) -> S {
+            f(ta.0.1, s)
+        }
+
+        #[inline]
+        fn foldl_once<B, S, F: FnOnce(S, B) -> S>(
+            ta: Applied1st<TupleConstructor, A, B>,
+            s: S,
+            f: F,
+        ) -> S {
+            f(s, ta.0.1)
+        }
+    }
+}
@@ -13,11 +13,14 @@ pub struct CloneVecConstructor;
 
 mod vec_impls {
     use crate::family_pattern::data::vec::VecConstructor;
+    use crate::family_pattern::typeclasses::applicative::ApplicativeMut;
     use crate::family_pattern::typeclasses::bind::BindMut;
+    use crate::family_pattern::typeclasses::foldable::FoldableMut;
     use crate::family_pattern::typeclasses::functor::FunctorMut;
     use crate::family_pattern::typeclasses::monoid::Monoid;
     use crate::family_pattern::typeclasses::pure::Pure;
     use crate::family_pattern::typeclasses::semigroup::Semigroup;
+    use crate::family_pattern::typeclasses::traverse::TraverseMut;
     use crate::family_pattern::{ConstructableTy1, TyConstructor1};
 
     impl<T> ConstructableTy1 for Vec<T> {
@@ -64,15 +67,47 @@ mod vec_impls {
             ta.into_iter().flat_map(f).collect()
         }
     }
+
+    impl FoldableMut for VecConstructor {
+        #[inline]
+        fn foldr_mut<A, S, F: FnMut(A, S) -> S>(ta: Vec<A>, s: S, mut f: F) -> S {
+            ta.into_iter().rev().fold(s, |s, a| f(a, s))
+        }
+
+        #[inline]
+        fn foldl_mut<A, S, F: FnMut(S, A) -> S>(ta: Vec<A>, s: S, f: F) -> S {
+            ta.into_iter().fold(s, f)
+        }
+    }
+
+    impl TraverseMut for VecConstructor {
+        #[inline]
+        fn traverse_mut<F: ApplicativeMut, A, B, _F: FnMut(A) -> F::TC1<B>>(
+            ta: Vec<A>,
+            mut f: _F,
+        ) -> F::TC1<Vec<B>> {
+            let capacity = ta.len();
+            ta.into_iter()
+                .fold(F::pure(Vec::with_capacity(capacity)), |acc, a| {
+                    F::lift_2a_mut(acc, f(a), |mut bs, b| {
+                        bs.push(b);
+                        bs
+                    })
+                })
+        }
+    }
 }
 
 mod clone_vec_impls {
     use crate::family_pattern::data::vec::{CloneVec, CloneVecConstructor};
+    use crate::family_pattern::typeclasses::constrained::ap::CAp;
+    use crate::family_pattern::typeclasses::constrained::bind::CBind;
+    use crate::family_pattern::typeclasses::constrained::functor::CFunctor;
+    use crate::family_pattern::typeclasses::constrained::pure::CPure;
     use crate::family_pattern::typeclasses::monoid::Monoid;
-    use crate::family_pattern::typeclasses::pure::Pure;
     use crate::family_pattern::typeclasses::semigroup::Semigroup;
-    use crate::family_pattern::{syntax::*, ConstructableTy1, TyConstructor1};
-    use misc::typelevel::clone::{is_clone, CloneInstance as _};
+    use crate::family_pattern::{ConstructableTy1, TyConstructor1};
+    use misc::typelevel::clone::{is_clone, CloneInstance as _, CloneWit};
 
     impl<T> Clone for CloneVec<T> {
         #[inline]
@@ -87,6 +122,13 @@ mod clone_vec_impls {
         }
     }
 
+    impl<T: Clone> From<Vec<T>> for CloneVec<T> {
+        #[inline]
+        fn from(value: Vec<T>) -> Self {
+            Self(value, is_clone())
+        }
+    }
+
     impl<T> ConstructableTy1 for CloneVec<T> {
         type GenericParameter1 = T;
         type Constructor = CloneVecConstructor;
@@ -94,6 +136,7 @@ mod clone_vec_impls {
 
     impl TyConstructor1 for CloneVecConstructor {
         type TC1<T> = CloneVec<T>;
+        type Witness<T> = CloneWit<T>;
     }
 
     // TODO: this simple "delegation" implementations should deffo be generated by Macro of some kind
@@ -119,35 +162,49 @@ mod clone_vec_impls {
         }
     }
 
-    // TODO: CAN'T EVEN DO THIS because `fa.1` is of type `CloneWit<A>` but I need `CloneWit<B>`
-    //       I need __SOME__ way of specifying that `B: Clone`, its really annoying that I can't do this...
-    // TODO: maybe the solution is to introduce something like "WeakFunctor"??
-    //       where it has `type Target<T> = Vec<T>` and
-    //       `fn fmap<A, B, F: Fn(A) -> B>(fa: CloneVec<A>, f: F) -> Vec<B>` ??
-    //       I just don't know any other way to do this...
-    // impl FunctorMut for CloneVecConstructor {
-    //     #[inline]
-    //     fn fmap_mut<A, B, F: FnMut(A) -> B>(fa: CloneVec<A>, f: F) -> CloneVec<B> {
-    //         CloneVec(fa.0.fmap_mut(f), fa.1)
-    //     }
-    // }
-
-    // TODO: same problem as above...
-
-    // impl Pure for VecConstructor {
-    //     #[inline]
-    //     fn pure<A>(a: A) -> Vec<A> {
-    //         vec![a]
-    //     }
-    // }
-
-    // impl BindMut for VecConstructor {
-    //     #[inline]
-    //     fn bind_mut<A, B, F: FnMut(A) -> Vec<B>>(ta: Vec<A>, f: F) -> Vec<B> {
-    //         ta.into_iter().flat_map(f).collect()
-    //     }
-    // }
-
-    // TODO: and this is where I WOULD have been able to implement "applicative" were it not
-    //       for the stupid type-constraint issue as above..................................
+    // the constrained typeclasses are given the `CloneWit` of the types they construct, which is
+    // what `CloneVec` needs to hold on to
+    impl CFunctor for CloneVecConstructor {
+        #[inline]
+        fn cfmap<A, B, F: Fn(A) -> B>(fa: CloneVec<A>, f: F, wit: CloneWit<B>) -> CloneVec<B> {
+            CloneVec(fa.0.into_iter().map(f).collect(), wit)
+        }
+    }
+
+    impl CPure for CloneVecConstructor {
+        #[inline]
+        fn cpure<A>(a: A, wit: CloneWit<A>) -> CloneVec<A> {
+            CloneVec(vec![a], wit)
+        }
+    }
+
+    impl CAp for CloneVecConstructor {
+        #[inline]
+        fn clift_2a<A, B, C, F: Fn(A, B) -> C>(
+            fa: CloneVec<A>,
+            fb: CloneVec<B>,
+            f: F,
+            wit: CloneWit<C>,
+        ) -> CloneVec<C> {
+            // every element is combined with every other element, so they need to be cloned
+            let mut lifted = Vec::with_capacity(fa.0.len().saturating_mul(fb.0.len()));
+            for a in &fa.0 {
+                for b in &fb.0 {
+                    lifted.push(f(a.clone_with(&fa.1), b.clone_with(&fb.1)));
+                }
+            }
+            CloneVec(lifted, wit)
+        }
+    }
+
+    impl CBind for CloneVecConstructor {
+        #[inline]
+        fn cbind<A, B, F: Fn(A) -> CloneVec<B>>(
+            ta: CloneVec<A>,
+            f: F,
+            wit: CloneWit<B>,
+        ) -> CloneVec<B> {
+            CloneVec(ta.0.into_iter().flat_map(|a| f(a).0).collect(), wit)
+        }
+    }
 }
@@ -1,27 +1,243 @@
-use crate::family_pattern::{ConstructableTy2, TyConstructor2, TC2};
+//! Type-level "flipping" of the generic positions of [`TyConstructor2`]s, i.e. `T<A,B>` to `T<B,A>`.
+//!
+//! Partially applying the flipped constructor fixes the position that
+//! [`Apply1st`](crate::family_pattern::apply::Apply1st) or
+//! [`Apply2nd`](crate::family_pattern::apply::Apply2nd) would otherwise leave free.
+//!
+//! E.g. `Apply1st<Flip<ResultConstructor>, E>` is the type-constructor for `Result<_,E>`, and it
+//! has the `Functor`/`Bind`/`Foldable` instances of `Apply2nd<ResultConstructor, E>`.
+
+use crate::family_pattern::{ConstructableTy2, TyConstructor2};
 use std::marker::PhantomData;
 
-// TODO: figure out how to represent type-level "flipping" of 2-constructor arguments
-// TODO: figure out how to "apply" 2-TypeConstructors to obtain 1-TypeConstuctors, and so on
-// TODO: with that figured out, expand typeclass hierarchy
+// TODO: expand typeclass hierarchy now that constructors can be flipped
 //       - bi-functors, bi-applicatives, etc.,
 //       - monad transformer encoding, how ??
 
+/// The type-constructor for `T<B,A>`, i.e. [`TyConstructor2`] `T` with its positions swapped.
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
 #[repr(transparent)]
 pub struct Flip<T: TyConstructor2>(PhantomData<T>);
 
+/// The type constructed by [`Flip`], wrapping `T<B,A>`.
+///
+/// It is a newtype-wrapper, because the foreign type `T<B,A>` already has a [`ConstructableTy2`]
+/// implementation pointing back at `T`; converting to and from it with [`FlipSyntax::flip`] and
+/// [`Flipped::unflip`] costs nothing at runtime.
 #[repr(transparent)]
-pub struct Flipped<T: ConstructableTy2>(
-    pub TC2<T::Constructor, T::GenericParameter1, T::GenericParameter2>,
-);
-
-// impl<T: ConstructableTy2> ConstructableTy2 for Flipped<T> {
-//     type GenericParameter1 = T::GenericParameter2;
-//     type GenericParameter2 = T::GenericParameter1;
-//     type Constructor = Flip<T::Constructor>;
-// }
-//
-// impl<T: TyConstructor2> TyConstructor2 for Flip<T> {
-//     type TC2<A, B> = Flipped<T::TC2<B, A>>;
-// }
+pub struct Flipped<T: TyConstructor2, A, B>(pub T::TC2<B, A>);
+
+/// An extension trait for flipping the generic positions of [`ConstructableTy2`] types.
+pub trait FlipSyntax: ConstructableTy2 {
+    /// Wrap `T<A,B>` into `Flipped<T,B,A>`, whose generic positions are swapped.
+    fn flip(self) -> Flipped<Self::Constructor, Self::GenericParameter2, Self::GenericParameter1>;
+}
+
+mod flip_impls {
+    #![allow(clippy::inline_always)]
+
+    use crate::family_pattern::flip::{Flip, FlipSyntax, Flipped};
+    use crate::family_pattern::{ConstructableTy2, ConstructableTyExt2 as _, TyConstructor2};
+
+    impl<T: TyConstructor2, A, B> ConstructableTy2 for Flipped<T, A, B> {
+        type GenericParameter1 = A;
+        type GenericParameter2 = B;
+        type Constructor = Flip<T>;
+    }
+
+    impl<T: TyConstructor2> TyConstructor2 for Flip<T> {
+        type TC2<A, B> = Flipped<T, A, B>;
+    }
+
+    impl<T: TyConstructor2, A, B> Flipped<T, A, B> {
+        /// Unwrap `Flipped<T,A,B>` back into `T<B,A>`, the inverse of [`FlipSyntax::flip`].
+        #[inline(always)]
+        pub fn unflip(self) -> T::TC2<B, A> {
+            self.0
+        }
+    }
+
+    impl<TA: ConstructableTy2> FlipSyntax for TA {
+        #[inline(always)]
+        fn flip(
+            self,
+        ) -> Flipped<Self::Constructor, Self::GenericParameter2, Self::GenericParameter1> {
+            Flipped(self.reify2())
+        }
+    }
+}
+
+mod std_impls {
+    use crate::family_pattern::TyConstructor2;
+    use crate::family_pattern::flip::Flipped;
+    use std::fmt::{self, Debug, Formatter};
+
+    impl<T: TyConstructor2, A, B> Clone for Flipped<T, A, B>
+    where
+        T::TC2<B, A>: Clone,
+    {
+        #[inline]
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+
+    impl<T: TyConstructor2, A, B> Copy for Flipped<T, A, B> where T::TC2<B, A>: Copy {}
+
+    impl<T: TyConstructor2, A, B> PartialEq for Flipped<T, A, B>
+    where
+        T::TC2<B, A>: PartialEq,
+    {
+        #[inline]
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl<T: TyConstructor2, A, B> Eq for Flipped<T, A, B> where T::TC2<B, A>: Eq {}
+
+    impl<T: TyConstructor2, A, B> Debug for Flipped<T, A, B>
+    where
+        T::TC2<B, A>: Debug,
+    {
+        #[inline]
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("Flipped").field(&self.0).finish()
+        }
+    }
+}
+
+// the instances of a partially applied `Flip<T>` are those of `T` partially applied at the other
+// position, i.e. `Flip<T><A,_> = T<_,A>` and `Flip<T><_,B> = T<B,_>`
+mod apply_impls {
+    #![allow(clippy::inline_always)]
+
+    use crate::family_pattern::apply::{Applied1st, Applied2nd, Apply1st, Apply2nd};
+    use crate::family_pattern::flip::{Flip, Flipped};
+    use crate::family_pattern::typeclasses::bind::BindOnce;
+    use crate::family_pattern::typeclasses::foldable::FoldableOnce;
+    use crate::family_pattern::typeclasses::functor::FunctorOnce;
+    use crate::family_pattern::{TyConstructor1, TyConstructor2};
+
+    // Within the implementations below, the where-clauses on `Apply1st<T,_>`/`Apply2nd<T,_>` shadow
+    // their `TyConstructor1` implementations, so that their constructed types no longer normalize to
+    // `Applied1st`/`Applied2nd`; these helpers convert between the two outside of those where-clauses.
+
+    #[inline(always)]
+    const fn applied1st<T: TyConstructor2, A, X>(
+        ta: T::TC2<A, X>,
+    ) -> <Apply1st<T, A> as TyConstructor1>::TC1<X> {
+        Applied1st(ta)
+    }
+
+    #[inline(always)]
+    fn unapplied1st<T: TyConstructor2, A, X>(
+        ta: <Apply1st<T, A> as TyConstructor1>::TC1<X>,
+    ) -> T::TC2<A, X> {
+        ta.0
+    }
+
+    #[inline(always)]
+    const fn applied2nd<T: TyConstructor2, A, X>(
+        ta: T::TC2<X, A>,
+    ) -> <Apply2nd<T, A> as TyConstructor1>::TC1<X> {
+        Applied2nd(ta)
+    }
+
+    #[inline(always)]
+    fn unapplied2nd<T: TyConstructor2, A, X>(
+        ta: <Apply2nd<T, A> as TyConstructor1>::TC1<X>,
+    ) -> T::TC2<X, A> {
+        ta.0
+    }
+
+    impl<T: TyConstructor2, A> FunctorOnce for Apply1st<Flip<T>, A>
+    where
+        Apply2nd<T, A>: FunctorOnce,
+    {
+        #[inline]
+        fn fmap_once<X, Y, F: FnOnce(X) -> Y>(
+            fa: Applied1st<Flip<T>, A, X>,
+            f: F,
+        ) -> Applied1st<Flip<T>, A, Y> {
+            let fb = Apply2nd::<T, A>::fmap_once(applied2nd::<T, A, X>(fa.0.0), f);
+            Applied1st(Flipped(unapplied2nd::<T, A, Y>(fb)))
+        }
+    }
+
+    impl<T: TyConstructor2, B> FunctorOnce for Apply2nd<Flip<T>, B>
+    where
+        Apply1st<T, B>: FunctorOnce,
+    {
+        #[inline]
+        fn fmap_once<X, Y, F: FnOnce(X) -> Y>(
+            fa: Applied2nd<Flip<T>, X, B>,
+            f: F,
+        ) -> Applied2nd<Flip<T>, Y, B> {
+            let fb = Apply1st::<T, B>::fmap_once(applied1st::<T, B, X>(fa.0.0), f);
+            Applied2nd(Flipped(unapplied1st::<T, B, Y>(fb)))
+        }
+    }
+
+    impl<T: TyConstructor2, A> BindOnce for Apply1st<Flip<T>, A>
+    where
+        Apply2nd<T, A>: BindOnce,
+    {
+        #[inline]
+        fn bind_once<X, Y, F: FnOnce(X) -> Applied1st<Flip<T>, A, Y>>(
+            ta: Applied1st<Flip<T>, A, X>,
+            f: F,
+        ) -> Applied1st<Flip<T>, A, Y> {
+            let tb = Apply2nd::<T, A>::bind_once(applied2nd::<T, A, X>(ta.0.0), |x| {
+                applied2nd::<T, A, Y>(f(x).0.0)
+            });
+            Applied1st(Flipped(unapplied2nd::<T, A, Y>(tb)))
+        }
+    }
+
+    impl<T: TyConstructor2, B> BindOnce for Apply2nd<Flip<T>, B>
+    where
+        Apply1st<T, B>: BindOnce,
+    {
+        #[inline]
+        fn bind_once<X, Y, F: FnOnce(X) -> Applied2nd<Flip<T>, Y, B>>(
+            ta: Applied2nd<Flip<T>, X, B>,
+            f: F,
+        ) -> Applied2nd<Flip<T>, Y, B> {
+            let tb = Apply1st::<T, B>::bind_once(applied1st::<T, B, X>(ta.0.0), |x| {
+                applied1st::<T, B, Y>(f(x).0.0)
+            });
+            Applied2nd(Flipped(unapplied1st::<T, B, Y>(tb)))
+        }
+    }
+
+    impl<T: TyConstructor2, A> FoldableOnce for Apply1st<Flip<T>, A>
+    where
+        Apply2nd<T, A>: FoldableOnce,
+    {
+        #[inline]
+        fn foldr_once<X, S, F: FnOnce(X, S) -> S>(ta: Applied1st<Flip<T>, A, X>, s: S, f: F) -> S {
+            Apply2nd::<T, A>::foldr_once(applied2nd::<T, A, X>(ta.0.0), s, f)
+        }
+
+        #[inline]
+        fn foldl_once<X, S, F: FnOnce(S, X) -> S>(ta: Applied1st<Flip<T>, A, X>, s: S, f: F) -> S {
+            Apply2nd::<T, A>::foldl_once(applied2nd::<T, A, X>(ta.0.0), s, f)
+        }
+    }
+
+    impl<T: TyConstructor2, B> FoldableOnce for Apply2nd<Flip<T>, B>
+    where
+        Apply1st<T, B>: FoldableOnce,
+    {
+        #[inline]
+        fn foldr_once<X, S, F: FnOnce(X, S) -> S>(ta: Applied2nd<Flip<T>, X, B>, s: S, f: F) -> S {
+            Apply1st::<T, B>::foldr_once(applied1st::<T, B, X>(ta.0.0), s, f)
+        }
+
+        #[inline]
+        fn foldl_once<X, S, F: FnOnce(S, X) -> S>(ta: Applied2nd<Flip<T>, X, B>, s: S, f: F) -> S {
+            Apply1st::<T, B>::foldl_once(applied1st::<T, B, X>(ta.0.0), s, f)
+        }
+    }
+}
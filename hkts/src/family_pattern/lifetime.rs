@@ -0,0 +1,102 @@
+//! Lifetime-parameterised type-constructors, for types which borrow their generic parameter.
+//!
+//! Types like `&'a A`, `&'a [A]` or `std::slice::Iter<'a, A>` cannot be constructed by
+//! [`TyConstructor1`](crate::family_pattern::TyConstructor1)s, because their generic parameter has
+//! to outlive the borrow, which `TC1<A>` has no way of stating; instead the lifetime of the borrow
+//! is a parameter of the [`TyConstructorL1`] trait itself.
+
+/// A trait for marker-types that represent type-constructors of concrete [`ConstructableTyL1`]
+/// types, i.e. `* -> *` types which borrow their generic parameter for the lifetime `'a`.
+pub trait TyConstructorL1<'a> {
+    /// An encoding of the type-constructor function, as a generic associated type, e.g.
+    /// `TC1<A> = &'a [A]` for a slice type-constructor.
+    type TC1<A: 'a>: ConstructableTyL1<'a, Constructor = Self, GenericParameter1 = A>;
+}
+
+/// The counterpart of [`TyConstructorL1`] for clone-on-write types, like `Cow<'a, A>`, which can
+/// only be constructed for generic parameters that are [`ToOwned`].
+///
+/// The bound lives on this trait alone, so that [`TyConstructorL1`] keeps constructing borrowed
+/// views of data that cannot be cloned.
+pub trait ToOwnedTyConstructorL1<'a> {
+    /// An encoding of the type-constructor function, as a generic associated type, e.g.
+    /// `TC1<A> = Cow<'a, A>` for a clone-on-write type-constructor.
+    type TC1<A: 'a + ToOwned + ?Sized>;
+}
+
+/// A helper-trait for quantifying over all types that [`TyConstructorL1`] can construct, see
+/// [`ForAllConstructedTy1`](crate::family_pattern::ForAllConstructedTy1).
+pub trait ForAllConstructedTyL1<'a, A: 'a>: TyConstructorL1<'a> {
+    /// The type that [`TyConstructorL1`] constructed.
+    type ConstructedType = Self::TC1<A>;
+}
+
+/// A trait for types that borrow their generic parameter for the lifetime `'a`, and thus are
+/// constructable by an associated [`TyConstructorL1`].
+pub trait ConstructableTyL1<'a> {
+    /// The positional generic type parameter `A` admitted by this type.
+    type GenericParameter1: 'a;
+
+    /// The associated [`TyConstructorL1`] of this
+    type Constructor: ForAllConstructedTyL1<'a, Self::GenericParameter1, ConstructedType = Self>;
+}
+
+/// An extension trait for [`ConstructableTyL1`] types that allows them to be "reified" into the
+/// types constructed by their associated [`TyConstructorL1`]s.
+///
+/// See [`ConstructableTyExt1`](crate::family_pattern::ConstructableTyExt1).
+#[const_trait]
+pub trait ConstructableTyExtL1<'a>: ConstructableTyL1<'a> {
+    /// A function for "reifying" [`ConstructableTyL1`] into the types constructed by their
+    /// associated [`TyConstructorL1`]s.
+    fn reify_l1(self) -> <Self::Constructor as TyConstructorL1<'a>>::TC1<Self::GenericParameter1>
+    where
+        Self: Sized;
+}
+
+pub mod alias {
+    use crate::family_pattern::lifetime::{
+        ConstructableTyL1, ToOwnedTyConstructorL1, TyConstructorL1,
+    };
+
+    // type aliases
+    pub type TCL1<'a, T, A> = <T as TyConstructorL1<'a>>::TC1<A>;
+    pub type CTL1<'a, T> = <T as ConstructableTyL1<'a>>::Constructor;
+    pub type TCOL1<'a, T, A> = <T as ToOwnedTyConstructorL1<'a>>::TC1<A>;
+
+    // trait aliases
+    pub trait ConstructableTySyntaxL1<'a, T: TyConstructorL1<'a>, A: 'a> =
+        ConstructableTyL1<'a, Constructor = T, GenericParameter1 = A>;
+}
+
+mod impls {
+    #![allow(clippy::inline_always, clippy::single_call_fn)]
+
+    use crate::family_pattern::lifetime::{
+        ConstructableTyExtL1, ConstructableTyL1, ForAllConstructedTyL1, TyConstructorL1,
+    };
+
+    // blanket `ForAllConstructedTyL1` implementation
+    impl<'a, T: TyConstructorL1<'a>, A: 'a> ForAllConstructedTyL1<'a, A> for T {}
+
+    // blanket `witness_l1` implementation
+    #[inline(always)]
+    const fn witness_l1<'a, T: TyConstructorL1<'a>, A: 'a>(
+        ta: <T as ForAllConstructedTyL1<'a, A>>::ConstructedType,
+    ) -> T::TC1<A> {
+        ta
+    }
+
+    // blanket `ConstructableTyExtL1` implementation
+    impl<'a, T: ConstructableTyL1<'a> + ?Sized> const ConstructableTyExtL1<'a> for T {
+        #[inline(always)]
+        fn reify_l1(
+            self,
+        ) -> <Self::Constructor as TyConstructorL1<'a>>::TC1<Self::GenericParameter1>
+        where
+            Self: Sized,
+        {
+            witness_l1::<'a, Self::Constructor, Self::GenericParameter1>(self)
+        }
+    }
+}
@@ -3,22 +3,34 @@
 
 pub mod data;
 
+pub mod apply;
+pub mod compose;
 pub mod flip;
 pub mod into;
+pub mod lifetime;
 pub mod ty_constructor;
 pub mod typeclasses;
 
 pub use re_exports::*;
 
 mod re_exports {
+    pub use crate::family_pattern::lifetime::alias::*;
+    pub use crate::family_pattern::lifetime::{
+        ConstructableTyExtL1, ConstructableTyL1, ForAllConstructedTyL1, ToOwnedTyConstructorL1,
+        TyConstructorL1,
+    };
     pub use crate::family_pattern::ty_constructor::alias::*;
     pub use crate::family_pattern::ty_constructor::*;
+    pub use hkts_proc::HktConstructor;
 }
 
 pub mod syntax {
     pub use crate::family_pattern::{
+        lifetime::alias::ConstructableTySyntaxL1,
         ty_constructor::alias::{
             ConstructableTySyntax1, ConstructableTySyntax2, ConstructableTySyntax3,
+            ConstructableTySyntax4, ConstructableTySyntax5, ConstructableTySyntax6,
+            ConstructableTySyntax7, ConstructableTySyntax8,
         },
         typeclasses::{
             ap::{
@@ -26,8 +38,14 @@ pub mod syntax {
                 Lift2ASyntax,
             },
             bind::{BindMutSyntax, BindOnceSyntax, BindSyntax},
-            foldable::{FoldableMutSyntax, FoldableOnceSyntax, FoldableSyntax},
-            functor::{FunctorMutSyntax, FunctorOnceSyntax, FunctorSyntax},
+            constrained::{
+                ap::{CApSyntax, CLift2ASyntax},
+                bind::CBindSyntax,
+                functor::CFunctorSyntax,
+                pure::CPureSyntax,
+            },
+            foldable::{FoldableL1Syntax, FoldableMutSyntax, FoldableOnceSyntax, FoldableSyntax},
+            functor::{FunctorL1Syntax, FunctorMutSyntax, FunctorOnceSyntax, FunctorSyntax},
             pure::PureSyntax,
             traverse::{TraverseMutSyntax, TraverseOnceSyntax, TraverseSyntax},
         },
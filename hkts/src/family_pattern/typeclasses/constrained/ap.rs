@@ -0,0 +1,103 @@
+use crate::family_pattern::{ConstructableTySyntax1, TyConstructor1};
+
+pub trait CAp: TyConstructor1 {
+    #[inline]
+    fn cap<A, B, F: Fn(A) -> B>(
+        ff: Self::TC1<F>,
+        fa: Self::TC1<A>,
+        wit: Self::Witness<B>,
+    ) -> Self::TC1<B> {
+        Self::clift_2a(ff, fa, move |f, a| f(a), wit)
+    }
+    fn clift_2a<A, B, C, F: Fn(A, B) -> C>(
+        fa: Self::TC1<A>,
+        fb: Self::TC1<B>,
+        f: F,
+        wit: Self::Witness<C>,
+    ) -> Self::TC1<C>;
+}
+
+pub trait CApSyntax<TC: CAp, A, B, F: Fn(A) -> B>: ConstructableTySyntax1<TC, F> {
+    fn cap(self, fa: TC::TC1<A>) -> TC::TC1<B>
+    where
+        TC::Witness<B>: Default;
+}
+
+pub trait CLift2ASyntax<
+    A,
+    B,
+    T: CAp,
+    TA: ConstructableTySyntax1<T, A>,
+    TB: ConstructableTySyntax1<T, B>,
+>
+{
+    fn clift_2a<C, F: Fn(A, B) -> C>(self, f: F) -> T::TC1<C>
+    where
+        T::Witness<C>: Default;
+}
+
+mod impls {
+    use crate::family_pattern::{
+        ConstructableTy1, ConstructableTyExt1 as _, ConstructableTySyntax1,
+        typeclasses::{
+            ap::Ap,
+            constrained::ap::{CAp, CApSyntax, CLift2ASyntax},
+        },
+    };
+
+    // blanket lifting of unconstrained implementations
+    impl<TC: Ap> CAp for TC {
+        #[inline]
+        fn cap<A, B, F: Fn(A) -> B>(
+            ff: Self::TC1<F>,
+            fa: Self::TC1<A>,
+            _: Self::Witness<B>,
+        ) -> Self::TC1<B> {
+            TC::ap(ff, fa)
+        }
+
+        #[inline]
+        fn clift_2a<A, B, C, F: Fn(A, B) -> C>(
+            fa: Self::TC1<A>,
+            fb: Self::TC1<B>,
+            f: F,
+            _: Self::Witness<C>,
+        ) -> Self::TC1<C> {
+            TC::lift_2a(fa, fb, f)
+        }
+    }
+
+    // blanket `*Syntax` implementations
+    impl<
+        TC: CAp,
+        A,
+        B,
+        F: Fn(A) -> B,
+        FA: ConstructableTy1<Constructor = TC, GenericParameter1 = F>,
+    > CApSyntax<TC, A, B, F> for FA
+    {
+        #[inline]
+        fn cap(self, fa: TC::TC1<A>) -> TC::TC1<B>
+        where
+            TC::Witness<B>: Default,
+        {
+            TC::cap(self.reify1(), fa, TC::Witness::<B>::default())
+        }
+    }
+    impl<A, B, T: CAp, TA: ConstructableTySyntax1<T, A>, TB: ConstructableTySyntax1<T, B>>
+        CLift2ASyntax<A, B, T, TA, TB> for (TA, TB)
+    {
+        #[inline]
+        fn clift_2a<C, F: Fn(A, B) -> C>(self, f: F) -> T::TC1<C>
+        where
+            T::Witness<C>: Default,
+        {
+            T::clift_2a(
+                self.0.reify1(),
+                self.1.reify1(),
+                f,
+                T::Witness::<C>::default(),
+            )
+        }
+    }
+}
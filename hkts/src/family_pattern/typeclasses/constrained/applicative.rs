@@ -0,0 +1,14 @@
+use crate::family_pattern::typeclasses::constrained::ap::CAp;
+use crate::family_pattern::typeclasses::constrained::functor::CFunctor;
+use crate::family_pattern::typeclasses::constrained::pure::CPure;
+
+pub trait CApplicative: CFunctor + CPure + CAp {}
+
+mod impls {
+    use crate::family_pattern::typeclasses::constrained::ap::CAp;
+    use crate::family_pattern::typeclasses::constrained::applicative::CApplicative;
+    use crate::family_pattern::typeclasses::constrained::functor::CFunctor;
+    use crate::family_pattern::typeclasses::constrained::pure::CPure;
+
+    impl<T: CFunctor + CPure + CAp> CApplicative for T {}
+}
@@ -0,0 +1,50 @@
+use crate::family_pattern::{ConstructableTySyntax1, TyConstructor1};
+
+pub trait CBind: TyConstructor1 {
+    fn cbind<A, B, F: Fn(A) -> Self::TC1<B>>(
+        ta: Self::TC1<A>,
+        f: F,
+        wit: Self::Witness<B>,
+    ) -> Self::TC1<B>;
+}
+
+pub trait CBindSyntax<T: CBind, A>: ConstructableTySyntax1<T, A> {
+    fn cbind<B, F: Fn(A) -> T::TC1<B>>(self, f: F) -> T::TC1<B>
+    where
+        T::Witness<B>: Default;
+}
+
+mod impls {
+    use crate::family_pattern::{
+        ConstructableTy1, ConstructableTyExt1 as _,
+        typeclasses::{
+            bind::Bind,
+            constrained::bind::{CBind, CBindSyntax},
+        },
+    };
+
+    // blanket lifting of unconstrained implementations
+    impl<T: Bind> CBind for T {
+        #[inline]
+        fn cbind<A, B, F: Fn(A) -> Self::TC1<B>>(
+            ta: Self::TC1<A>,
+            f: F,
+            _: Self::Witness<B>,
+        ) -> Self::TC1<B> {
+            T::bind(ta, f)
+        }
+    }
+
+    // blanket `*Syntax` implementations
+    impl<T: CBind, A, FA: ConstructableTy1<Constructor = T, GenericParameter1 = A>>
+        CBindSyntax<T, A> for FA
+    {
+        #[inline]
+        fn cbind<B, F: Fn(A) -> T::TC1<B>>(self, f: F) -> T::TC1<B>
+        where
+            T::Witness<B>: Default,
+        {
+            T::cbind(self.reify1(), f, T::Witness::<B>::default())
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::family_pattern::{ConstructableTySyntax1, TyConstructor1};
+
+pub trait CFunctor: TyConstructor1 {
+    fn cfmap<A, B, F: Fn(A) -> B>(fa: Self::TC1<A>, f: F, wit: Self::Witness<B>) -> Self::TC1<B>;
+}
+
+pub trait CFunctorSyntax<T: CFunctor, A>: ConstructableTySyntax1<T, A> {
+    fn cfmap<B, F: Fn(A) -> B>(self, f: F) -> T::TC1<B>
+    where
+        T::Witness<B>: Default;
+}
+
+mod impls {
+    use crate::family_pattern::{
+        ConstructableTy1, ConstructableTyExt1 as _,
+        typeclasses::{
+            constrained::functor::{CFunctor, CFunctorSyntax},
+            functor::Functor,
+        },
+    };
+
+    // blanket lifting of unconstrained implementations
+    impl<T: Functor> CFunctor for T {
+        #[inline]
+        fn cfmap<A, B, F: Fn(A) -> B>(fa: Self::TC1<A>, f: F, _: Self::Witness<B>) -> Self::TC1<B> {
+            T::fmap(fa, f)
+        }
+    }
+
+    // blanket `*Syntax` implementations
+    impl<T: CFunctor, A, FA: ConstructableTy1<Constructor = T, GenericParameter1 = A>>
+        CFunctorSyntax<T, A> for FA
+    {
+        #[inline]
+        fn cfmap<B, F: Fn(A) -> B>(self, f: F) -> T::TC1<B>
+        where
+            T::Witness<B>: Default,
+        {
+            T::cfmap(self.reify1(), f, T::Witness::<B>::default())
+        }
+    }
+}
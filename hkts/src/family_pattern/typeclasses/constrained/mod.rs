@@ -0,0 +1,17 @@
+//! Constrained variants of the typeclasses, for type-constructors which can only construct types
+//! from generic parameters satisfying some constraints, e.g. `CloneVec<T>` needing `T: Clone`.
+//!
+//! Every operation producing a constructed type requires the [`TyConstructor1::Witness`] of its
+//! generic parameter, which the `*Syntax` traits conjure up with [`Default`]; the unconstrained
+//! typeclasses are lifted into these, with the default witness ignored. Unlike the unconstrained
+//! typeclasses there are no `*Mut`/`*Once` variants, as their reverse-hierarchy blanket
+//! implementations would overlap with the lifting ones.
+//!
+//! [`TyConstructor1::Witness`]: crate::family_pattern::TyConstructor1::Witness
+
+pub mod ap;
+pub mod applicative;
+pub mod bind;
+pub mod functor;
+pub mod monad;
+pub mod pure;
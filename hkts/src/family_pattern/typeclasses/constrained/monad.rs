@@ -0,0 +1,12 @@
+use crate::family_pattern::typeclasses::constrained::applicative::CApplicative;
+use crate::family_pattern::typeclasses::constrained::bind::CBind;
+
+pub trait CMonad: CApplicative + CBind {}
+
+mod impls {
+    use crate::family_pattern::typeclasses::constrained::applicative::CApplicative;
+    use crate::family_pattern::typeclasses::constrained::bind::CBind;
+    use crate::family_pattern::typeclasses::constrained::monad::CMonad;
+
+    impl<T: CApplicative + CBind> CMonad for T {}
+}
@@ -0,0 +1,40 @@
+use crate::family_pattern::{ConstructableTySyntax1, TyConstructor1};
+
+pub trait CPure: TyConstructor1 {
+    fn cpure<A>(a: A, wit: Self::Witness<A>) -> Self::TC1<A>;
+}
+
+pub trait CPureSyntax<T: CPure, A> {
+    fn cpure<TA: ConstructableTySyntax1<T, A>>(self) -> T::TC1<A>
+    where
+        T::Witness<A>: Default;
+}
+
+mod impls {
+    use crate::family_pattern::{
+        ConstructableTySyntax1,
+        typeclasses::{
+            constrained::pure::{CPure, CPureSyntax},
+            pure::Pure,
+        },
+    };
+
+    // blanket lifting of unconstrained implementations
+    impl<T: Pure> CPure for T {
+        #[inline]
+        fn cpure<A>(a: A, _: Self::Witness<A>) -> Self::TC1<A> {
+            T::pure(a)
+        }
+    }
+
+    // blanket `*Syntax` implementations
+    impl<T: CPure, A> CPureSyntax<T, A> for A {
+        #[inline]
+        fn cpure<TA: ConstructableTySyntax1<T, A>>(self) -> T::TC1<A>
+        where
+            T::Witness<A>: Default,
+        {
+            T::cpure(self, T::Witness::<A>::default())
+        }
+    }
+}
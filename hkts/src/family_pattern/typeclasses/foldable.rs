@@ -1,6 +1,8 @@
-use crate::family_pattern::typeclasses::monoid::Monoid;
 use crate::family_pattern::ConstructableTySyntax1;
+use crate::family_pattern::ConstructableTySyntaxL1;
 use crate::family_pattern::TyConstructor1;
+use crate::family_pattern::TyConstructorL1;
+use crate::family_pattern::typeclasses::monoid::Monoid;
 
 pub trait Foldable: TyConstructor1 {
     fn foldr<A, S, F: Fn(A, S) -> S>(ta: Self::TC1<A>, s: S, f: F) -> S;
@@ -50,6 +52,14 @@ pub trait FoldableOnce: TyConstructor1 {
     }
 }
 
+/// The borrowing counterpart of [`Foldable`] for [`TyConstructorL1`]s, which folds over the
+/// borrowed generic parameters, without cloning them.
+pub trait FoldableL1<'a>: TyConstructorL1<'a> {
+    fn foldr_ref<A: 'a, S, F: FnMut(&'a A, S) -> S>(ta: Self::TC1<A>, s: S, f: F) -> S;
+
+    fn foldl_ref<A: 'a, S, F: FnMut(S, &'a A) -> S>(ta: Self::TC1<A>, s: S, f: F) -> S;
+}
+
 pub trait FoldableSyntax<T: Foldable, A>: ConstructableTySyntax1<T, A> {
     fn foldr<S, F: Fn(A, S) -> S>(self, s: S, f: F) -> S;
 
@@ -80,14 +90,22 @@ pub trait FoldableOnceSyntax<T: Foldable, A>: ConstructableTySyntax1<T, A> {
     fn foldl_map_once<M: Monoid, F: FnOnce(A) -> M>(self, f: F) -> M;
 }
 
+pub trait FoldableL1Syntax<'a, T: FoldableL1<'a>, A: 'a>:
+    ConstructableTySyntaxL1<'a, T, A>
+{
+    fn foldr_ref<S, F: FnMut(&'a A, S) -> S>(self, s: S, f: F) -> S;
+
+    fn foldl_ref<S, F: FnMut(S, &'a A) -> S>(self, s: S, f: F) -> S;
+}
+
 mod impls {
     use crate::family_pattern::{
-        syntax::*, typeclasses::{
-            foldable::{Foldable, FoldableMut, FoldableOnce},
+        ConstructableTy1, ConstructableTyExt1 as _, ConstructableTyExtL1 as _, ConstructableTyL1,
+        syntax::*,
+        typeclasses::{
+            foldable::{Foldable, FoldableL1, FoldableMut, FoldableOnce},
             monoid::Monoid,
         },
-        ConstructableTy1,
-        ConstructableTyExt1 as _,
     };
 
     // blanket `Fn*` reverse-hierarchy implementations
@@ -204,4 +222,21 @@ mod impls {
             T::foldl_map_once(self.reify1(), f)
         }
     }
+    impl<
+        'a,
+        T: FoldableL1<'a>,
+        A: 'a,
+        TA: ConstructableTyL1<'a, Constructor = T, GenericParameter1 = A>,
+    > FoldableL1Syntax<'a, T, A> for TA
+    {
+        #[inline]
+        fn foldr_ref<S, F: FnMut(&'a A, S) -> S>(self, s: S, f: F) -> S {
+            T::foldr_ref(self.reify_l1(), s, f)
+        }
+
+        #[inline]
+        fn foldl_ref<S, F: FnMut(S, &'a A) -> S>(self, s: S, f: F) -> S {
+            T::foldl_ref(self.reify_l1(), s, f)
+        }
+    }
 }
@@ -1,4 +1,6 @@
-use crate::family_pattern::{ConstructableTySyntax1, TyConstructor1};
+use crate::family_pattern::{
+    ConstructableTySyntax1, ConstructableTySyntaxL1, TC1, TyConstructor1, TyConstructorL1,
+};
 use func::constant;
 
 pub trait Functor: TyConstructor1 {
@@ -23,6 +25,16 @@ pub trait FunctorOnce: TyConstructor1 {
     fn fmap_once<A, B, F: FnOnce(A) -> B>(fa: Self::TC1<A>, f: F) -> Self::TC1<B>;
 }
 
+/// The borrowing counterpart of [`Functor`] for [`TyConstructorL1`]s, which maps the borrowed
+/// generic parameters into the type-constructor of their owned counterparts, without cloning them.
+pub trait FunctorL1<'a>: TyConstructorL1<'a> {
+    /// The type-constructor of the owned counterparts of the constructed types, e.g. `Vec<_>` for
+    /// `&'a [_]`.
+    type Owned: TyConstructor1;
+
+    fn fmap_ref<A: 'a, B, F: FnMut(&'a A) -> B>(fa: Self::TC1<A>, f: F) -> TC1<Self::Owned, B>;
+}
+
 pub trait FunctorSyntax<T: Functor, A>: ConstructableTySyntax1<T, A> {
     fn fmap<B, F: Fn(A) -> B>(self, f: F) -> T::TC1<B>;
 
@@ -39,11 +51,16 @@ pub trait FunctorOnceSyntax<T: Functor, A>: ConstructableTySyntax1<T, A> {
     fn fmap_once<B, F: FnOnce(A) -> B>(self, f: F) -> T::TC1<B>;
 }
 
+pub trait FunctorL1Syntax<'a, T: FunctorL1<'a>, A: 'a>: ConstructableTySyntaxL1<'a, T, A> {
+    fn fmap_ref<B, F: FnMut(&'a A) -> B>(self, f: F) -> TC1<T::Owned, B>;
+}
+
 mod impls {
     use crate::family_pattern::{
-        syntax::*, typeclasses::functor::{Functor, FunctorMut, FunctorOnce},
-        ConstructableTy1,
-        ConstructableTyExt1 as _,
+        ConstructableTy1, ConstructableTyExt1 as _, ConstructableTyExtL1 as _, ConstructableTyL1,
+        TC1,
+        syntax::*,
+        typeclasses::functor::{Functor, FunctorL1, FunctorMut, FunctorOnce},
     };
 
     // blanket `Fn*` reverse-hierarchy implementations
@@ -95,4 +112,16 @@ mod impls {
             T::fmap_once(self.reify1(), f)
         }
     }
+    impl<
+        'a,
+        T: FunctorL1<'a>,
+        A: 'a,
+        FA: ConstructableTyL1<'a, Constructor = T, GenericParameter1 = A>,
+    > FunctorL1Syntax<'a, T, A> for FA
+    {
+        #[inline]
+        fn fmap_ref<B, F: FnMut(&'a A) -> B>(self, f: F) -> TC1<T::Owned, B> {
+            T::fmap_ref(self.reify_l1(), f)
+        }
+    }
 }
@@ -1,6 +1,7 @@
 pub mod ap;
 pub mod applicative;
 pub mod bind;
+pub mod constrained;
 pub mod foldable;
 pub mod functor;
 pub mod monad;
@@ -4,6 +4,9 @@
 #![feature(const_trait_impl)]
 #![feature(try_trait_v2)]
 
+// lets the code generated by `hkts_proc` refer to this crate by name from within it as well
+extern crate self as hkts;
+
 pub mod family_pattern;
 
 pub(crate) mod private {
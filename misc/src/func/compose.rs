@@ -3,6 +3,9 @@
 use std::marker::PhantomData;
 
 /// Struct capturing function composition.
+///
+/// It is callable like any other closure, and so also implements [`F1`](crate::func::f1::F1) and
+/// its `Mut`/`Once` variants, when `f` and `g` do.
 #[derive(Eq, PartialEq, Debug, Hash)]
 pub struct Compose<F, G, B> {
     f: F,
@@ -10,6 +13,53 @@ pub struct Compose<F, G, B> {
     _b: PhantomData<B>,
 }
 
+/// Struct capturing the composition of a whole tuple of functions, applied from first to last.
+///
+/// This is the flat equivalent of the nested `Compose<Compose<..>, ..>` chains built by
+/// [`Compose::chain`], so it does not grow a level of nesting per function.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct ComposeAll<Fs> {
+    fs: Fs,
+}
+
+/// Marker for every type that isn't a [`Compose`], i.e. the functions at the leaves of a chain.
+///
+/// [`Flatten`] stops recursing at these, so a function which merely captures a [`Compose`] isn't
+/// a leaf either, and the chains containing it cannot be flattened.
+pub auto trait Uncomposed {}
+
+/// A trait for collecting the functions of nested `Compose<Compose<..>, ..>` chains into a tuple.
+///
+/// It is implemented for the chains built by [`Compose::chain`],
+/// [`F1OnceExt::chain_ref`](crate::func::f1::F1OnceExt::chain_ref) and
+/// [`F1OnceExt::chain_deref`](crate::func::f1::F1OnceExt::chain_deref), as well as for tuples of
+/// upto 8 functions, which are flat already.
+pub trait Flatten {
+    /// The tuple of composed functions, applied from first to last.
+    type Fns;
+
+    /// Returns the tuple of composed functions, applied from first to last.
+    fn into_fns(self) -> Self::Fns;
+}
+
+/// A trait for appending an element to the end of a tuple, used by [`Flatten`].
+pub trait TupleAppend<T> {
+    /// The tuple with `T` appended.
+    type Output;
+
+    /// Append `t` to the end of this tuple.
+    fn append(self, t: T) -> Self::Output;
+}
+
+/// Flatten a nested chain of upto 8 functions, or compose a tuple of them, applied from first to
+/// last, see [`ComposeAll`].
+#[inline(always)]
+pub fn compose_all<C: Flatten>(chain: C) -> ComposeAll<C::Fns> {
+    ComposeAll {
+        fs: chain.into_fns(),
+    }
+}
+
 mod compose_impls {
     use crate::func::compose::Compose;
     use crate::func::f1::F1Once;
@@ -110,3 +160,198 @@ mod fn_impls {
         }
     }
 }
+
+mod flatten_impls {
+    use crate::func::compose::{Compose, Flatten, TupleAppend, Uncomposed};
+
+    impl<F, G, B> !Uncomposed for Compose<F, G, B> {}
+
+    // the innermost composition of a chain, whose `f` is a leaf
+    impl<F: Uncomposed, G, B> Flatten for Compose<F, G, B> {
+        type Fns = (F, G);
+
+        #[inline(always)]
+        fn into_fns(self) -> Self::Fns {
+            (self.f, self.g)
+        }
+    }
+
+    // chains nest to the left, so `g` is always a leaf
+    impl<F, G0, B0, G, B> Flatten for Compose<Compose<F, G0, B0>, G, B>
+    where
+        Compose<F, G0, B0>: Flatten<Fns: TupleAppend<G>>,
+    {
+        type Fns = <<Compose<F, G0, B0> as Flatten>::Fns as TupleAppend<G>>::Output;
+
+        #[inline(always)]
+        fn into_fns(self) -> Self::Fns {
+            self.f.into_fns().append(self.g)
+        }
+    }
+}
+
+// a tuple of functions is already flat, and can have another one appended unless it is full
+macro_rules! impl_flatten_for_tuple {
+    ($($G:ident),+) => {
+        impl<$($G),+> Flatten for ($($G,)+) {
+            type Fns = Self;
+
+            #[inline(always)]
+            fn into_fns(self) -> Self::Fns {
+                self
+            }
+        }
+    };
+    ($($G:ident),+; $T:ident) => {
+        impl_flatten_for_tuple!($($G),+);
+
+        impl<$($G,)+ $T> TupleAppend<$T> for ($($G,)+) {
+            type Output = ($($G,)+ $T);
+
+            #[allow(non_snake_case)]
+            #[inline(always)]
+            fn append(self, t: $T) -> Self::Output {
+                let ($($G,)+) = self;
+                ($($G,)+ t)
+            }
+        }
+    };
+}
+
+mod flatten_tuple_impls {
+    use crate::func::compose::{Flatten, TupleAppend};
+
+    impl_flatten_for_tuple!(G0; G1);
+    impl_flatten_for_tuple!(G0, G1; G2);
+    impl_flatten_for_tuple!(G0, G1, G2; G3);
+    impl_flatten_for_tuple!(G0, G1, G2, G3; G4);
+    impl_flatten_for_tuple!(G0, G1, G2, G3, G4; G5);
+    impl_flatten_for_tuple!(G0, G1, G2, G3, G4, G5; G6);
+    impl_flatten_for_tuple!(G0, G1, G2, G3, G4, G5, G6; G7);
+    impl_flatten_for_tuple!(G0, G1, G2, G3, G4, G5, G6, G7);
+}
+
+mod compose_all_impls {
+    use crate::func::compose::ComposeAll;
+
+    impl<Fs> ComposeAll<Fs> {
+        /// Returns the tuple of composed functions.
+        #[inline(always)]
+        pub fn into_inner(self) -> Fs {
+            self.fs
+        }
+    }
+}
+
+// every stage is bound on its own input and output types, each fed the output of the stage before
+macro_rules! impl_fn_for_compose_all {
+    ($R:ident; $(($In:ident, $G:ident, $Out:ident, $idx:tt)),+) => {
+        impl<A, $($G, $Out),+> FnOnce<(A,)> for ComposeAll<($($G,)+)>
+        where
+            $($G: F1Once<$In, Output = $Out>,)+
+        {
+            type Output = $R;
+
+            #[inline(always)]
+            extern "rust-call" fn call_once(self, (a,): (A,)) -> Self::Output {
+                $(let a = self.fs.$idx.call1_once(a);)+
+                a
+            }
+        }
+
+        impl<A, $($G, $Out),+> FnMut<(A,)> for ComposeAll<($($G,)+)>
+        where
+            $($G: F1Mut<$In, Output = $Out>,)+
+        {
+            #[inline(always)]
+            extern "rust-call" fn call_mut(&mut self, (a,): (A,)) -> Self::Output {
+                $(let a = self.fs.$idx.call1_mut(a);)+
+                a
+            }
+        }
+
+        impl<A, $($G, $Out),+> Fn<(A,)> for ComposeAll<($($G,)+)>
+        where
+            $($G: F1<$In, Output = $Out>,)+
+        {
+            #[inline(always)]
+            extern "rust-call" fn call(&self, (a,): (A,)) -> Self::Output {
+                $(let a = self.fs.$idx.call1(a);)+
+                a
+            }
+        }
+    };
+}
+
+mod compose_all_fn_impls {
+    use crate::func::compose::ComposeAll;
+    use crate::func::f1::{F1, F1Mut, F1Once};
+
+    impl_fn_for_compose_all!(B0; (A, G0, B0, 0));
+    impl_fn_for_compose_all!(B1; (A, G0, B0, 0), (B0, G1, B1, 1));
+    impl_fn_for_compose_all!(B2; (A, G0, B0, 0), (B0, G1, B1, 1), (B1, G2, B2, 2));
+    impl_fn_for_compose_all!(
+        B3; (A, G0, B0, 0), (B0, G1, B1, 1), (B1, G2, B2, 2), (B2, G3, B3, 3)
+    );
+    impl_fn_for_compose_all!(
+        B4; (A, G0, B0, 0), (B0, G1, B1, 1), (B1, G2, B2, 2), (B2, G3, B3, 3), (B3, G4, B4, 4)
+    );
+    impl_fn_for_compose_all!(
+        B5; (A, G0, B0, 0), (B0, G1, B1, 1), (B1, G2, B2, 2), (B2, G3, B3, 3), (B3, G4, B4, 4),
+        (B4, G5, B5, 5)
+    );
+    impl_fn_for_compose_all!(
+        B6; (A, G0, B0, 0), (B0, G1, B1, 1), (B1, G2, B2, 2), (B2, G3, B3, 3), (B3, G4, B4, 4),
+        (B4, G5, B5, 5), (B5, G6, B6, 6)
+    );
+    impl_fn_for_compose_all!(
+        B7; (A, G0, B0, 0), (B0, G1, B1, 1), (B1, G2, B2, 2), (B2, G3, B3, 3), (B3, G4, B4, 4),
+        (B4, G5, B5, 5), (B5, G6, B6, 6), (B6, G7, B7, 7)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::func::compose::compose_all;
+    use crate::func::f1::F1OnceExt as _;
+
+    #[test]
+    fn flattens_chains() {
+        let chain = || {
+            (|n: u32| n.saturating_add(1))
+                .chain(|n: u32| n.saturating_mul(2))
+                .chain(|n: u32| n.to_string())
+        };
+        let (inc, double, show) = compose_all(chain()).into_inner();
+        assert_eq!(show(double(inc(3))), "8");
+        assert_eq!(compose_all(chain())(3), chain()(3));
+    }
+
+    #[test]
+    fn flattens_ref_chains() {
+        let chain = || {
+            (|n: u32| n.to_string())
+                .chain_ref(|s: &String| s.len())
+                .chain_ref(|len: &usize| len.saturating_mul(2))
+        };
+        assert_eq!(compose_all(chain())(123), 6);
+        assert_eq!(compose_all(chain())(123), chain()(123));
+    }
+
+    #[test]
+    fn flattens_deref_chains() {
+        let chain = || {
+            (|n: u32| vec![n; 3])
+                .chain_deref(|ns: &[u32]| ns.iter().sum::<u32>().to_string())
+                .chain_deref(|s: &str| s.len())
+        };
+        assert_eq!(compose_all(chain())(4), 2);
+        assert_eq!(compose_all(chain())(4), chain()(4));
+    }
+
+    #[test]
+    fn composes_tuples() {
+        let compose = compose_all((|n: u32| n.saturating_add(1), |n: u32| n.saturating_mul(2)));
+        assert_eq!(compose(3), 8);
+    }
+}
@@ -0,0 +1,270 @@
+//! An operator-overloaded function wrapper, for composing functions in point-free style, e.g.
+//! `Fun::new(parse) >> validate >> save` rather than `parse.chain(validate).chain(save)`.
+
+#![allow(clippy::inline_always)]
+
+use std::marker::PhantomData;
+
+/// Newtype around a function from `A`, overloading operators for composition and application:
+///  - `f >> g` is forward composition, i.e. [`Compose`](crate::func::compose::Compose) `g . f`;
+///  - `f << g` is backward composition, i.e. `f . g`, where `g` must be a [`Fun`] as well;
+///  - `f | a` applies `f` to `a`;
+///  - `f * g` is applicative-style pairing, i.e. a function producing `(f(a), g(a))`.
+///
+/// Unlike closures, it carries the type of its argument, so that the operators can name the types
+/// of the compositions they produce. The results of every operator but `|` are again [`Fun`]s, so
+/// they chain, and every [`Fun`] is callable like the function it wraps.
+#[derive(Eq, PartialEq, Debug, Hash)]
+pub struct Fun<F, A> {
+    f: F,
+    _a: PhantomData<fn(A)>,
+}
+
+/// Struct capturing the pairing of two functions of the same argument, see [`Fun`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct Both<F, G> {
+    f: F,
+    g: G,
+}
+
+mod fun_impls {
+    use crate::func::f1::F1Once;
+    use crate::func::fun::Fun;
+    use std::marker::PhantomData;
+
+    impl<F, A> Fun<F, A> {
+        /// See [`Fun`]
+        #[inline(always)]
+        pub const fn new(f: F) -> Self
+        where
+            F: F1Once<A>,
+        {
+            Self { f, _a: PhantomData }
+        }
+
+        /// Unwraps the function, e.g. to hand it to an API that names its type
+        #[inline(always)]
+        pub fn into_inner(self) -> F {
+            self.f
+        }
+    }
+
+    impl<F, A> Clone for Fun<F, A>
+    where
+        F: Clone,
+    {
+        #[inline(always)]
+        fn clone(&self) -> Self {
+            Self {
+                f: self.f.clone(),
+                _a: PhantomData,
+            }
+        }
+    }
+
+    impl<F, A> Copy for Fun<F, A> where F: Copy {}
+}
+
+mod ops_impls {
+    use crate::func::compose::Compose;
+    use crate::func::f1::F1Once;
+    use crate::func::fun::{Both, Fun};
+    use std::ops::{BitOr, Mul, Shl, Shr};
+
+    impl<F, G, A> Shr<G> for Fun<F, A>
+    where
+        F: F1Once<A>,
+        G: F1Once<F::Output>,
+    {
+        type Output = Fun<Compose<F, G, F::Output>, A>;
+
+        #[inline(always)]
+        fn shr(self, rhs: G) -> Self::Output {
+            Fun::new(Compose::compose(self.f, rhs))
+        }
+    }
+
+    impl<F, G, A, X> Shl<Fun<G, X>> for Fun<F, A>
+    where
+        F: F1Once<A>,
+        G: F1Once<X, Output = A>,
+    {
+        type Output = Fun<Compose<G, F, A>, X>;
+
+        #[inline(always)]
+        fn shl(self, rhs: Fun<G, X>) -> Self::Output {
+            Fun::new(Compose::compose(rhs.f, self.f))
+        }
+    }
+
+    impl<F, A> BitOr<A> for Fun<F, A>
+    where
+        F: F1Once<A>,
+    {
+        type Output = F::Output;
+
+        #[inline(always)]
+        fn bitor(self, rhs: A) -> Self::Output {
+            self.f.call1_once(rhs)
+        }
+    }
+
+    impl<F, G, A> Mul<G> for Fun<F, A>
+    where
+        F: F1Once<A>,
+        G: F1Once<A>,
+        A: Clone,
+    {
+        type Output = Fun<Both<F, G>, A>;
+
+        #[inline(always)]
+        fn mul(self, rhs: G) -> Self::Output {
+            Fun::new(Both { f: self.f, g: rhs })
+        }
+    }
+}
+
+mod fn_impls {
+    use crate::func::f1::{F1, F1Mut, F1Once};
+    use crate::func::fun::{Both, Fun};
+
+    impl<F, A> FnOnce<(A,)> for Fun<F, A>
+    where
+        F: F1Once<A>,
+    {
+        type Output = F::Output;
+
+        #[inline(always)]
+        extern "rust-call" fn call_once(self, args: (A,)) -> Self::Output {
+            self.f.call1_once(args.0)
+        }
+    }
+
+    impl<F, A> FnMut<(A,)> for Fun<F, A>
+    where
+        F: F1Mut<A>,
+    {
+        #[inline(always)]
+        extern "rust-call" fn call_mut(&mut self, args: (A,)) -> Self::Output {
+            self.f.call1_mut(args.0)
+        }
+    }
+
+    impl<F, A> Fn<(A,)> for Fun<F, A>
+    where
+        F: F1<A>,
+    {
+        #[inline(always)]
+        extern "rust-call" fn call(&self, args: (A,)) -> Self::Output {
+            self.f.call1(args.0)
+        }
+    }
+
+    impl<F, G, A> FnOnce<(A,)> for Both<F, G>
+    where
+        F: F1Once<A>,
+        G: F1Once<A>,
+        A: Clone,
+    {
+        type Output = (F::Output, G::Output);
+
+        #[inline(always)]
+        extern "rust-call" fn call_once(self, (a,): (A,)) -> Self::Output {
+            (self.f.call1_once(a.clone()), self.g.call1_once(a))
+        }
+    }
+
+    impl<F, G, A> FnMut<(A,)> for Both<F, G>
+    where
+        F: F1Mut<A>,
+        G: F1Mut<A>,
+        A: Clone,
+    {
+        #[inline(always)]
+        extern "rust-call" fn call_mut(&mut self, (a,): (A,)) -> Self::Output {
+            (self.f.call1_mut(a.clone()), self.g.call1_mut(a))
+        }
+    }
+
+    impl<F, G, A> Fn<(A,)> for Both<F, G>
+    where
+        F: F1<A>,
+        G: F1<A>,
+        A: Clone,
+    {
+        #[inline(always)]
+        extern "rust-call" fn call(&self, (a,): (A,)) -> Self::Output {
+            (self.f.call1(a.clone()), self.g.call1(a))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::func::f2::F2OnceExt as _;
+    use crate::func::f3::F3OnceExt as _;
+    use crate::func::fun::Fun;
+
+    fn inc(n: u32) -> u32 {
+        n.saturating_add(1)
+    }
+
+    fn double(n: u32) -> u32 {
+        n.saturating_mul(2)
+    }
+
+    fn show(n: u32) -> String {
+        n.to_string()
+    }
+
+    fn add(a: u32, b: u32) -> u32 {
+        a.saturating_add(b)
+    }
+
+    fn add3(a: u32, b: u32, c: u32) -> u32 {
+        a.saturating_add(b).saturating_add(c)
+    }
+
+    #[test]
+    fn composes_forwards_and_backwards() {
+        assert_eq!((Fun::new(inc) >> double) | 3, 8);
+        assert_eq!((Fun::new(inc) << Fun::new(double)) | 3, 7);
+        assert_eq!((Fun::new(inc) >> double >> show) | 3, "8");
+        assert_eq!(
+            (Fun::new(show) << Fun::new(inc) << Fun::new(double)) | 3,
+            "7"
+        );
+    }
+
+    #[test]
+    fn applies_with_pipe() {
+        assert_eq!(Fun::new(inc) | 3, 4);
+        assert_eq!(Fun::new(|s: String| s.len()) | String::from("four"), 4);
+    }
+
+    #[test]
+    fn pairs_with_mul() {
+        let sum = |(a, b): (u32, u32)| a.saturating_add(b);
+        let len = |v: Vec<u32>| v.len();
+        let total = |v: Vec<u32>| v.iter().sum::<u32>();
+        assert_eq!((Fun::new(inc) * double) | 3, (4, 6));
+        assert_eq!(((Fun::new(inc) * double) >> sum) | 3, 10);
+        assert_eq!((Fun::new(len) * total) | vec![1, 2, 3], (3, 6));
+    }
+
+    #[test]
+    fn calls_like_the_wrapped_function() {
+        let composed = Fun::new(inc) >> double;
+        let both = Fun::new(inc) * double;
+        assert_eq!((composed(3), composed(4)), (8, 10));
+        assert_eq!((both(3), both(4)), ((4, 6), (5, 8)));
+    }
+
+    #[test]
+    fn wraps_curried_stages() {
+        assert_eq!((Fun::new(add.curry().apply(1)) >> double) | 3, 8);
+        assert_eq!((Fun::new(inc) >> add.curry().apply(1)) | 3, 5);
+        assert_eq!((Fun::new(add3.curry().apply(1).apply(2)) >> show) | 3, "6");
+        assert_eq!(Fun::new(add.curry().apply(1)).into_inner()(2), 3);
+    }
+}
@@ -10,6 +10,8 @@ use std::ops::Deref;
 
 pub mod compose;
 pub mod curry2;
+pub mod fun;
+pub mod partial;
 
 #[inline]
 pub const fn tuple2<T1, T2>(t1: T1, t2: T2) -> (T1, T2) {
@@ -312,3 +314,513 @@ pub mod f2 {
         }
     }
 }
+
+/// Generates the `FN*` traits of a given arity in `$fmod`, alongside the partial-application types
+/// returned by their `curry()` extension in `$cmod`, following the layout of [`f2`] and [`curry2`].
+///
+/// Each `AppliedK` alias is the curried function after `K` of its arguments were applied. The
+/// positions not applied yet hold a `PhantomData<fn(A)>`, so that, like [`curry2::Applied1`],
+/// the stages are `Copy` whenever the function and the applied arguments are.
+macro_rules! impl_fn_arity {
+    (
+        $fmod:ident::{$F:ident, $FMut:ident, $FOnce:ident, $FOnceExt:ident}
+            ($call:ident, $call_mut:ident, $call_once:ident),
+        $cmod:ident::$Curry:ident[$Applied0:ident, $Applied1:ident $(, $Applied:ident)*],
+        ($A1:ident $a1:ident $(, $A:ident $a:ident)+)
+    ) => {
+        pub mod $fmod {
+            // the higher arities are bound to exceed clippy's limit on arguments
+            #![allow(clippy::too_many_arguments)]
+
+            use crate::func::f1::F1Once;
+
+            pub trait $F<$A1 $(, $A)+>: $FMut<$A1 $(, $A)+> {
+                fn $call(&self, $a1: $A1 $(, $a: $A)+) -> Self::Output;
+            }
+
+            pub trait $FMut<$A1 $(, $A)+>: $FOnce<$A1 $(, $A)+> {
+                fn $call_mut(&mut self, $a1: $A1 $(, $a: $A)+) -> Self::Output;
+            }
+
+            pub trait $FOnce<$A1 $(, $A)+> {
+                type Output;
+                impl_fn_arity!(@applied_decl [$Applied1 $(, $Applied)*], [$($A),+]);
+                type Curried: F1Once<$A1, Output = Self::$Applied1>;
+
+                fn $call_once(self, $a1: $A1 $(, $a: $A)+) -> Self::Output;
+            }
+
+            #[const_trait]
+            pub trait $FOnceExt<$A1 $(, $A)+>: $FOnce<$A1 $(, $A)+> {
+                fn curry(self) -> Self::Curried;
+            }
+
+            mod impls {
+                use crate::func::$cmod::{$Applied0, $Applied1 $(, $Applied)*};
+                use crate::func::$fmod::{$F, $FMut, $FOnce, $FOnceExt};
+
+                impl<F, $A1 $(, $A)+, R> $F<$A1 $(, $A)+> for F
+                where
+                    F: Fn($A1 $(, $A)+) -> R,
+                {
+                    #[inline(always)]
+                    fn $call(&self, $a1: $A1 $(, $a: $A)+) -> Self::Output {
+                        self($a1 $(, $a)+)
+                    }
+                }
+
+                impl<F, $A1 $(, $A)+, R> $FMut<$A1 $(, $A)+> for F
+                where
+                    F: FnMut($A1 $(, $A)+) -> R,
+                {
+                    #[inline(always)]
+                    fn $call_mut(&mut self, $a1: $A1 $(, $a: $A)+) -> Self::Output {
+                        self($a1 $(, $a)+)
+                    }
+                }
+
+                impl<F, $A1 $(, $A)+, R> $FOnce<$A1 $(, $A)+> for F
+                where
+                    F: FnOnce($A1 $(, $A)+) -> R,
+                {
+                    type Output = R;
+                    impl_fn_arity!(@applied_impl [$Applied1 $(, $Applied)*], all: [$A1 $(, $A)+]);
+                    type Curried = $Applied0<Self, $A1 $(, $A)+, R>;
+
+                    #[inline(always)]
+                    fn $call_once(self, $a1: $A1 $(, $a: $A)+) -> Self::Output {
+                        self($a1 $(, $a)+)
+                    }
+                }
+
+                impl<F, $A1 $(, $A)+, R> const $FOnceExt<$A1 $(, $A)+> for F
+                where
+                    F: $FOnce<
+                            $A1 $(, $A)+,
+                            Output = R,
+                            Curried = $Applied0<Self, $A1 $(, $A)+, R>,
+                        >,
+                {
+                    #[inline(always)]
+                    fn curry(self) -> Self::Curried {
+                        $Applied0::curry(self)
+                    }
+                }
+            }
+        }
+
+        pub mod $cmod {
+            use crate::func::$fmod::{$F, $FMut, $FOnce};
+            use crate::typelevel::maybe::Just;
+            use std::marker::PhantomData;
+
+            #[derive(Eq, PartialEq, Debug, Hash)]
+            pub struct $Curry<F, $A1 $(, $A)+, R: ?Sized> {
+                f: F,
+                _r: PhantomData<R>,
+                $a1: $A1,
+                $($a: $A,)+
+            }
+
+            impl<F, $A1 $(, $A)+, R> $Applied0<F, $A1 $(, $A)+, R>
+            where
+                F: $FOnce<$A1 $(, $A)+, Output = R>,
+            {
+                #[inline(always)]
+                pub const fn curry(f: F) -> Self {
+                    Self {
+                        f,
+                        _r: PhantomData,
+                        $a1: PhantomData,
+                        $($a: PhantomData,)+
+                    }
+                }
+
+                #[inline(always)]
+                pub fn uncurry(self) -> F {
+                    self.f
+                }
+            }
+
+            impl_fn_arity!(@stage
+                $Curry, [$F, $FMut, $FOnce], ($call, $call_mut, $call_once),
+                all: [$A1 $(, $A)+],
+                done: [],
+                next: $A1 $a1,
+                rest: [$($A $a),+],
+                aliases: [$Applied0, $Applied1 $(, $Applied)*]
+            );
+        }
+    };
+
+    // the `AppliedK` associated types of `$FOnce`, i.e. the curried function after `K` of its
+    // arguments were applied, each of which applies the next argument
+    (@applied_decl [$Cur:ident, $Next:ident $(, $More:ident)*], [$N:ident $(, $P:ident)*]) => {
+        type $Cur: F1Once<$N, Output = Self::$Next>;
+        impl_fn_arity!(@applied_decl [$Next $(, $More)*], [$($P),*]);
+    };
+    (@applied_decl [$Cur:ident], [$N:ident]) => {
+        type $Cur: F1Once<$N, Output = Self::Output>;
+    };
+
+    // the `AppliedK` associated types of the `$FOnce` implementation, i.e. the `AppliedK` aliases
+    (@applied_impl [$Cur:ident $(, $More:ident)*], all: [$($All:ident),+]) => {
+        type $Cur = $Cur<Self, $($All),+, R>;
+        impl_fn_arity!(@applied_impl [$($More),*], all: [$($All),+]);
+    };
+    (@applied_impl [], all: [$($All:ident),+]) => {};
+
+    // a stage which applies an argument and returns the next stage
+    (@stage
+        $Curry:ident, [$F:ident, $FMut:ident, $FOnce:ident], $calls:tt,
+        all: [$($All:ident),+],
+        done: [$($D:ident $d:ident),*],
+        next: $N:ident $n:ident,
+        rest: [$P1:ident $p1:ident $(, $P:ident $p:ident)*],
+        aliases: [$Cur:ident, $Next:ident $(, $More:ident)*]
+    ) => {
+        pub type $Cur<F, $($All),+, R> =
+            $Curry<
+                F,
+                $(Just<$D>,)*
+                PhantomData<fn($N)>,
+                PhantomData<fn($P1)>,
+                $(PhantomData<fn($P)>,)*
+                R,
+            >;
+
+        impl<F, $($All),+, R> $Cur<F, $($All),+, R>
+        where
+            F: $FOnce<$($All),+, Output = R>,
+        {
+            #[inline(always)]
+            pub fn apply(self, $n: $N) -> $Next<F, $($All),+, R> {
+                $Curry {
+                    f: self.f,
+                    _r: PhantomData,
+                    $($d: self.$d,)*
+                    $n: Just($n),
+                    $p1: PhantomData,
+                    $($p: PhantomData,)*
+                }
+            }
+
+            #[inline(always)]
+            pub fn apply_mut(&mut self, $n: $N) -> $Next<F, $($All),+, R>
+            where
+                F: Clone,
+                $($D: Clone,)*
+            {
+                self.apply_ref($n)
+            }
+
+            #[inline(always)]
+            pub fn apply_ref(&self, $n: $N) -> $Next<F, $($All),+, R>
+            where
+                F: Clone,
+                $($D: Clone,)*
+            {
+                $Curry {
+                    f: self.f.clone(),
+                    _r: PhantomData,
+                    $($d: self.$d.clone(),)*
+                    $n: Just($n),
+                    $p1: PhantomData,
+                    $($p: PhantomData,)*
+                }
+            }
+        }
+
+        impl<F, $($All),+, R> Clone for $Cur<F, $($All),+, R>
+        where
+            F: Clone,
+            $($D: Clone,)*
+        {
+            #[inline(always)]
+            fn clone(&self) -> Self {
+                Self {
+                    f: self.f.clone(),
+                    _r: PhantomData,
+                    $($d: self.$d.clone(),)*
+                    $n: PhantomData,
+                    $p1: PhantomData,
+                    $($p: PhantomData,)*
+                }
+            }
+        }
+
+        impl<F, $($All),+, R> Copy for $Cur<F, $($All),+, R>
+        where
+            F: Copy,
+            $($D: Copy,)*
+        {
+        }
+
+        impl<F, $($All),+, R> FnOnce<($N,)> for $Cur<F, $($All),+, R>
+        where
+            F: FnOnce($($All),+) -> R,
+        {
+            type Output = $Next<F, $($All),+, R>;
+
+            #[inline(always)]
+            extern "rust-call" fn call_once(self, args: ($N,)) -> Self::Output {
+                self.apply(args.0)
+            }
+        }
+
+        impl<F, $($All),+, R> FnMut<($N,)> for $Cur<F, $($All),+, R>
+        where
+            Self: FnOnce<($N,), Output = $Next<F, $($All),+, R>>,
+            F: FnMut($($All),+) -> R + Clone,
+            $($D: Clone,)*
+        {
+            #[inline(always)]
+            extern "rust-call" fn call_mut(&mut self, args: ($N,)) -> Self::Output {
+                self.apply_mut(args.0)
+            }
+        }
+
+        impl<F, $($All),+, R> Fn<($N,)> for $Cur<F, $($All),+, R>
+        where
+            Self: FnMut<($N,), Output = $Next<F, $($All),+, R>>,
+            F: Fn($($All),+) -> R + Clone,
+            $($D: Clone,)*
+        {
+            #[inline(always)]
+            extern "rust-call" fn call(&self, args: ($N,)) -> Self::Output {
+                self.apply_ref(args.0)
+            }
+        }
+
+        impl_fn_arity!(@stage
+            $Curry, [$F, $FMut, $FOnce], $calls,
+            all: [$($All),+],
+            done: [$($D $d,)* $N $n],
+            next: $P1 $p1,
+            rest: [$($P $p),*],
+            aliases: [$Next $(, $More)*]
+        );
+    };
+
+    // the final stage, which applies the last argument and calls the function
+    (@stage
+        $Curry:ident, [$F:ident, $FMut:ident, $FOnce:ident],
+        ($call:ident, $call_mut:ident, $call_once:ident),
+        all: [$($All:ident),+],
+        done: [$($D:ident $d:ident),*],
+        next: $N:ident $n:ident,
+        rest: [],
+        aliases: [$Cur:ident]
+    ) => {
+        pub type $Cur<F, $($All),+, R> = $Curry<F, $(Just<$D>,)* PhantomData<fn($N)>, R>;
+
+        impl<F, $($All),+, R> $Cur<F, $($All),+, R>
+        where
+            F: $FOnce<$($All),+, Output = R>,
+        {
+            #[inline(always)]
+            pub fn apply(self, $n: $N) -> R {
+                self.f.$call_once($(self.$d.0,)* $n)
+            }
+
+            #[inline(always)]
+            pub fn apply_mut(&mut self, $n: $N) -> R
+            where
+                F: $FMut<$($All),+, Output = R>,
+                $($D: Clone,)*
+            {
+                self.f.$call_mut($(self.$d.0.clone(),)* $n)
+            }
+
+            #[inline(always)]
+            pub fn apply_ref(&self, $n: $N) -> R
+            where
+                F: $F<$($All),+, Output = R>,
+                $($D: Clone,)*
+            {
+                self.f.$call($(self.$d.0.clone(),)* $n)
+            }
+        }
+
+        impl<F, $($All),+, R> Clone for $Cur<F, $($All),+, R>
+        where
+            F: Clone,
+            $($D: Clone,)*
+        {
+            #[inline(always)]
+            fn clone(&self) -> Self {
+                Self {
+                    f: self.f.clone(),
+                    _r: PhantomData,
+                    $($d: self.$d.clone(),)*
+                    $n: PhantomData,
+                }
+            }
+        }
+
+        impl<F, $($All),+, R> Copy for $Cur<F, $($All),+, R>
+        where
+            F: Copy,
+            $($D: Copy,)*
+        {
+        }
+
+        impl<F, $($All),+, R> FnOnce<($N,)> for $Cur<F, $($All),+, R>
+        where
+            F: FnOnce($($All),+) -> R,
+        {
+            type Output = R;
+
+            #[inline(always)]
+            extern "rust-call" fn call_once(self, args: ($N,)) -> Self::Output {
+                self.apply(args.0)
+            }
+        }
+
+        impl<F, $($All),+, R> FnMut<($N,)> for $Cur<F, $($All),+, R>
+        where
+            Self: FnOnce<($N,), Output = R>,
+            F: FnMut($($All),+) -> R,
+            $($D: Clone,)*
+        {
+            #[inline(always)]
+            extern "rust-call" fn call_mut(&mut self, args: ($N,)) -> Self::Output {
+                self.apply_mut(args.0)
+            }
+        }
+
+        impl<F, $($All),+, R> Fn<($N,)> for $Cur<F, $($All),+, R>
+        where
+            Self: FnMut<($N,), Output = R>,
+            F: Fn($($All),+) -> R,
+            $($D: Clone,)*
+        {
+            #[inline(always)]
+            extern "rust-call" fn call(&self, args: ($N,)) -> Self::Output {
+                self.apply_ref(args.0)
+            }
+        }
+    };
+}
+
+impl_fn_arity!(
+    f3::{F3, F3Mut, F3Once, F3OnceExt}(call3, call3_mut, call3_once),
+    curry3::Curry3[Applied0, Applied1, Applied2],
+    (A1 a1, A2 a2, A3 a3)
+);
+impl_fn_arity!(
+    f4::{F4, F4Mut, F4Once, F4OnceExt}(call4, call4_mut, call4_once),
+    curry4::Curry4[Applied0, Applied1, Applied2, Applied3],
+    (A1 a1, A2 a2, A3 a3, A4 a4)
+);
+impl_fn_arity!(
+    f5::{F5, F5Mut, F5Once, F5OnceExt}(call5, call5_mut, call5_once),
+    curry5::Curry5[Applied0, Applied1, Applied2, Applied3, Applied4],
+    (A1 a1, A2 a2, A3 a3, A4 a4, A5 a5)
+);
+impl_fn_arity!(
+    f6::{F6, F6Mut, F6Once, F6OnceExt}(call6, call6_mut, call6_once),
+    curry6::Curry6[Applied0, Applied1, Applied2, Applied3, Applied4, Applied5],
+    (A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6)
+);
+impl_fn_arity!(
+    f7::{F7, F7Mut, F7Once, F7OnceExt}(call7, call7_mut, call7_once),
+    curry7::Curry7[Applied0, Applied1, Applied2, Applied3, Applied4, Applied5, Applied6],
+    (A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7)
+);
+impl_fn_arity!(
+    f8::{F8, F8Mut, F8Once, F8OnceExt}(call8, call8_mut, call8_once),
+    curry8::Curry8[Applied0, Applied1, Applied2, Applied3, Applied4, Applied5, Applied6, Applied7],
+    (A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8)
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::func::curry3::{Applied1, Applied2};
+    use crate::func::f1::F1Once as _;
+    use crate::func::f3::{F3 as _, F3Mut as _, F3Once as _, F3OnceExt};
+    use crate::func::f8::{F8 as _, F8OnceExt as _};
+
+    /// Combines the arguments as decimal digits, so that their order shows in the result.
+    fn digits(a: u32, b: u32, c: u32) -> u32 {
+        a.saturating_mul(100)
+            .saturating_add(b.saturating_mul(10))
+            .saturating_add(c)
+    }
+
+    #[allow(clippy::many_single_char_names, clippy::too_many_arguments)]
+    fn octet(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8, g: u8, h: u8) -> [u8; 8] {
+        [a, b, c, d, e, f, g, h]
+    }
+
+    /// Applies the first argument, relying only on what [`F3Once::Curried`] promises to return.
+    fn apply_first<F: F3OnceExt<u32, u32, u32>>(f: F, a: u32) -> F::Applied1 {
+        f.curry().call1_once(a)
+    }
+
+    #[test]
+    fn calls_every_flavour() {
+        assert_eq!(digits.call3(1, 2, 3), 123);
+        let mut calls = 0_u32;
+        let mut counted = |a: u32, b: u32, c: u32| {
+            calls = calls.saturating_add(1);
+            digits(a, b, c)
+        };
+        assert_eq!(counted.call3_mut(3, 2, 1), 321);
+        assert_eq!(counted.call3_once(1, 1, 1), 111);
+        assert_eq!(calls, 2);
+        assert_eq!(
+            octet.call8(1, 2, 3, 4, 5, 6, 7, 8),
+            [1, 2, 3, 4, 5, 6, 7, 8]
+        );
+    }
+
+    #[test]
+    fn curries_one_argument_at_a_time() {
+        let applied1: Applied1<_, u32, u32, u32, u32> = digits.curry().apply(1);
+        let applied2: Applied2<_, u32, u32, u32, u32> = applied1.apply_ref(2);
+        assert_eq!(applied2.apply_ref(3), 123);
+        assert_eq!(applied2.apply(4), 124);
+        assert_eq!(applied1.apply(5).apply(6), 156);
+        assert_eq!(digits.curry()(7)(8)(9), 789);
+        assert_eq!(
+            octet.curry()(1)(2)(3)(4)(5)(6)(7)(8),
+            [1, 2, 3, 4, 5, 6, 7, 8]
+        );
+
+        let mut calls = 0_u32;
+        let mut counted = (|a: u32, b: u32, c: u32| {
+            calls = calls.saturating_add(1);
+            digits(a, b, c)
+        })
+        .curry()
+        .apply(1)
+        .apply(2);
+        assert_eq!(counted.apply_mut(3), 123);
+        assert_eq!(counted.apply_mut(4), 124);
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn copies_stages_with_unapplied_owned_arguments() {
+        fn join(a: u32, b: String, c: String) -> String {
+            [a.to_string(), b, c].concat()
+        }
+
+        let applied1 = join.curry().apply(1);
+        let copied = applied1;
+        assert_eq!(
+            applied1.apply(String::from("2")).apply(String::from("3")),
+            "123"
+        );
+        assert_eq!(
+            copied.apply(String::from("4")).apply(String::from("5")),
+            "145"
+        );
+    }
+
+    #[test]
+    fn names_curried_outputs() {
+        assert_eq!(apply_first(digits, 1).call1_once(2).call1_once(3), 123);
+    }
+}
@@ -0,0 +1,345 @@
+//! Partial application of two-argument functions at any argument position, where the positions
+//! left open are placeholders which the resulting closure takes as its arguments.
+//!
+//! Positions are fixed either with the [`partial!`](crate::partial) macro, e.g. `partial!(f, _, 5)`,
+//! or with [`F2PartialExt::apply_at`], e.g. `f.apply_at::<1>(5)`.
+
+#![allow(clippy::inline_always)]
+
+/// A function with some of its arguments fixed.
+///
+/// `Args` is a tuple of [`Maybe`](crate::typelevel::maybe::Maybe)s, holding either the fixed
+/// argument ([`Just`]) or a placeholder ([`Nothing`]) for each position. It is callable with the
+/// placeholder arguments, in order.
+///
+/// [`Just`]: crate::typelevel::maybe::Just
+/// [`Nothing`]: crate::typelevel::maybe::Nothing
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+pub struct Partial<F, Args> {
+    f: F,
+    args: Args,
+}
+
+/// Type-level indexing into a tuple of arguments, used by [`F2PartialExt::apply_at`] to work out
+/// the type of the fixed argument and the placeholders left by fixing it.
+pub trait ArgAt<const I: usize> {
+    /// The type of the argument at position `I`
+    type Arg;
+    /// The [`Partial`] arguments with only position `I` fixed
+    type Fixed;
+
+    fn fixed(arg: Self::Arg) -> Self::Fixed;
+}
+
+/// Extension trait for fixing a single argument of any two-argument function by its position.
+pub trait F2PartialExt<A, B>: Sized {
+    /// Fix the argument at position `I`, leaving a placeholder for the other one.
+    fn apply_at<const I: usize>(
+        self,
+        arg: <(A, B) as ArgAt<I>>::Arg,
+    ) -> Partial<Self, <(A, B) as ArgAt<I>>::Fixed>
+    where
+        (A, B): ArgAt<I>;
+}
+
+/// Partially apply a two-argument function, where `_` leaves a placeholder for that position and
+/// any other expression fixes it, e.g. `partial!(f, _, 5)` is a closure equivalent to `|a| f(a, 5)`.
+#[macro_export]
+macro_rules! partial {
+    ($f:expr, _, _ $(,)?) => {
+        $crate::func::partial::Partial::new(
+            $f,
+            (
+                $crate::typelevel::maybe::Nothing::new(),
+                $crate::typelevel::maybe::Nothing::new(),
+            ),
+        )
+    };
+    ($f:expr, _, $b:expr $(,)?) => {
+        $crate::func::partial::Partial::new(
+            $f,
+            (
+                $crate::typelevel::maybe::Nothing::new(),
+                $crate::typelevel::maybe::Just($b),
+            ),
+        )
+    };
+    ($f:expr, $a:expr, _ $(,)?) => {
+        $crate::func::partial::Partial::new(
+            $f,
+            (
+                $crate::typelevel::maybe::Just($a),
+                $crate::typelevel::maybe::Nothing::new(),
+            ),
+        )
+    };
+    ($f:expr, $a:expr, $b:expr $(,)?) => {
+        $crate::func::partial::Partial::new(
+            $f,
+            (
+                $crate::typelevel::maybe::Just($a),
+                $crate::typelevel::maybe::Just($b),
+            ),
+        )
+    };
+}
+
+mod partial_impls {
+    use crate::func::f2::F2Once;
+    use crate::func::partial::{ArgAt, F2PartialExt, Partial};
+    use crate::typelevel::maybe::{Just, Nothing};
+
+    impl<F, Args> Partial<F, Args> {
+        /// See [`Partial`]
+        #[inline(always)]
+        pub const fn new(f: F, args: Args) -> Self {
+            Self { f, args }
+        }
+
+        #[inline(always)]
+        pub fn into_inner(self) -> (F, Args) {
+            (self.f, self.args)
+        }
+    }
+
+    impl<A, B> ArgAt<0> for (A, B) {
+        type Arg = A;
+        type Fixed = (Just<A>, Nothing<B>);
+
+        #[inline(always)]
+        fn fixed(arg: Self::Arg) -> Self::Fixed {
+            (Just(arg), Nothing::new())
+        }
+    }
+
+    impl<A, B> ArgAt<1> for (A, B) {
+        type Arg = B;
+        type Fixed = (Nothing<A>, Just<B>);
+
+        #[inline(always)]
+        fn fixed(arg: Self::Arg) -> Self::Fixed {
+            (Nothing::new(), Just(arg))
+        }
+    }
+
+    impl<F, A, B> F2PartialExt<A, B> for F
+    where
+        F: F2Once<A, B>,
+    {
+        #[inline(always)]
+        fn apply_at<const I: usize>(
+            self,
+            arg: <(A, B) as ArgAt<I>>::Arg,
+        ) -> Partial<Self, <(A, B) as ArgAt<I>>::Fixed>
+        where
+            (A, B): ArgAt<I>,
+        {
+            Partial::new(self, <(A, B) as ArgAt<I>>::fixed(arg))
+        }
+    }
+}
+
+mod fn_impls {
+    use crate::func::f2::{F2, F2Mut, F2Once};
+    use crate::func::partial::Partial;
+    use crate::typelevel::maybe::{Just, Nothing};
+
+    // nothing fixed -------------------------------------------------------------------------------
+
+    impl<F, A, B> FnOnce<(A, B)> for Partial<F, (Nothing<A>, Nothing<B>)>
+    where
+        F: F2Once<A, B>,
+    {
+        type Output = F::Output;
+
+        #[inline(always)]
+        extern "rust-call" fn call_once(self, (a, b): (A, B)) -> Self::Output {
+            self.f.call2_once(a, b)
+        }
+    }
+
+    impl<F, A, B> FnMut<(A, B)> for Partial<F, (Nothing<A>, Nothing<B>)>
+    where
+        F: F2Mut<A, B>,
+    {
+        #[inline(always)]
+        extern "rust-call" fn call_mut(&mut self, (a, b): (A, B)) -> Self::Output {
+            self.f.call2_mut(a, b)
+        }
+    }
+
+    impl<F, A, B> Fn<(A, B)> for Partial<F, (Nothing<A>, Nothing<B>)>
+    where
+        F: F2<A, B>,
+    {
+        #[inline(always)]
+        extern "rust-call" fn call(&self, (a, b): (A, B)) -> Self::Output {
+            self.f.call2(a, b)
+        }
+    }
+
+    // first argument fixed ------------------------------------------------------------------------
+
+    impl<F, A, B> FnOnce<(B,)> for Partial<F, (Just<A>, Nothing<B>)>
+    where
+        F: F2Once<A, B>,
+    {
+        type Output = F::Output;
+
+        #[inline(always)]
+        extern "rust-call" fn call_once(self, (b,): (B,)) -> Self::Output {
+            self.f.call2_once(self.args.0.0, b)
+        }
+    }
+
+    impl<F, A, B> FnMut<(B,)> for Partial<F, (Just<A>, Nothing<B>)>
+    where
+        F: F2Mut<A, B>,
+        A: Clone,
+    {
+        #[inline(always)]
+        extern "rust-call" fn call_mut(&mut self, (b,): (B,)) -> Self::Output {
+            self.f.call2_mut(self.args.0.0.clone(), b)
+        }
+    }
+
+    impl<F, A, B> Fn<(B,)> for Partial<F, (Just<A>, Nothing<B>)>
+    where
+        F: F2<A, B>,
+        A: Clone,
+    {
+        #[inline(always)]
+        extern "rust-call" fn call(&self, (b,): (B,)) -> Self::Output {
+            self.f.call2(self.args.0.0.clone(), b)
+        }
+    }
+
+    // second argument fixed -----------------------------------------------------------------------
+
+    impl<F, A, B> FnOnce<(A,)> for Partial<F, (Nothing<A>, Just<B>)>
+    where
+        F: F2Once<A, B>,
+    {
+        type Output = F::Output;
+
+        #[inline(always)]
+        extern "rust-call" fn call_once(self, (a,): (A,)) -> Self::Output {
+            self.f.call2_once(a, self.args.1.0)
+        }
+    }
+
+    impl<F, A, B> FnMut<(A,)> for Partial<F, (Nothing<A>, Just<B>)>
+    where
+        F: F2Mut<A, B>,
+        B: Clone,
+    {
+        #[inline(always)]
+        extern "rust-call" fn call_mut(&mut self, (a,): (A,)) -> Self::Output {
+            self.f.call2_mut(a, self.args.1.0.clone())
+        }
+    }
+
+    impl<F, A, B> Fn<(A,)> for Partial<F, (Nothing<A>, Just<B>)>
+    where
+        F: F2<A, B>,
+        B: Clone,
+    {
+        #[inline(always)]
+        extern "rust-call" fn call(&self, (a,): (A,)) -> Self::Output {
+            self.f.call2(a, self.args.1.0.clone())
+        }
+    }
+
+    // both arguments fixed ------------------------------------------------------------------------
+
+    impl<F, A, B> FnOnce<()> for Partial<F, (Just<A>, Just<B>)>
+    where
+        F: F2Once<A, B>,
+    {
+        type Output = F::Output;
+
+        #[inline(always)]
+        extern "rust-call" fn call_once(self, (): ()) -> Self::Output {
+            let (Just(a), Just(b)) = self.args;
+            self.f.call2_once(a, b)
+        }
+    }
+
+    impl<F, A, B> FnMut<()> for Partial<F, (Just<A>, Just<B>)>
+    where
+        F: F2Mut<A, B>,
+        A: Clone,
+        B: Clone,
+    {
+        #[inline(always)]
+        extern "rust-call" fn call_mut(&mut self, (): ()) -> Self::Output {
+            self.f
+                .call2_mut(self.args.0.0.clone(), self.args.1.0.clone())
+        }
+    }
+
+    impl<F, A, B> Fn<()> for Partial<F, (Just<A>, Just<B>)>
+    where
+        F: F2<A, B>,
+        A: Clone,
+        B: Clone,
+    {
+        #[inline(always)]
+        extern "rust-call" fn call(&self, (): ()) -> Self::Output {
+            self.f.call2(self.args.0.0.clone(), self.args.1.0.clone())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::func::partial::F2PartialExt as _;
+
+    /// Calls the function with both arguments in turn, through the [`FnMut`] trait.
+    fn call_twice<A, B, F: FnMut(A) -> B>(mut f: F, first: A, second: A) -> (B, B) {
+        (f(first), f(second))
+    }
+
+    #[test]
+    fn fixes_with_placeholders() {
+        let sub = u32::saturating_sub;
+        assert_eq!(partial!(sub, _, _)(10, 3), 7);
+        assert_eq!(partial!(sub, 10, _)(3), 7);
+        assert_eq!(partial!(sub, _, 3)(10), 7);
+        assert_eq!(partial!(sub, 10, 3)(), 7);
+        assert_eq!(partial!(sub, 10, _,)(20), 0);
+    }
+
+    #[test]
+    fn fixes_by_position() {
+        let sub = u32::saturating_sub;
+        let from_ten = sub.apply_at::<0>(10);
+        let minus_three = sub.apply_at::<1>(3);
+        assert_eq!((from_ten(3), from_ten(20)), (7, 0));
+        assert_eq!((minus_three(10), minus_three(2)), (7, 0));
+    }
+
+    #[test]
+    fn clones_fixed_arguments() {
+        let mut calls = 0_u32;
+        let push = |mut v: Vec<u32>, n: u32| {
+            calls = calls.saturating_add(1);
+            v.push(n);
+            v
+        };
+        assert_eq!(
+            call_twice(partial!(push, vec![1], _), 2, 3),
+            (vec![1, 2], vec![1, 3])
+        );
+        assert_eq!(calls, 2);
+
+        let prepend = |n: u32, mut v: Vec<u32>| {
+            v.insert(0, n);
+            v
+        };
+        assert_eq!(
+            call_twice(prepend.apply_at::<1>(vec![9]), 1, 2),
+            (vec![1, 9], vec![2, 9])
+        );
+    }
+}
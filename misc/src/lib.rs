@@ -7,6 +7,8 @@
 #![feature(const_trait_impl)]
 #![feature(fn_traits)]
 #![feature(non_lifetime_binders)]
+#![feature(auto_traits)]
+#![feature(negative_impls)]
 
 mod dyn_traits;
 pub mod func;